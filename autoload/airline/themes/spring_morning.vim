@@ -0,0 +1,104 @@
+" vim-airline theme for spring-morning colorscheme
+"
+" Author: rhysd <lin90162@yahoo.co.jp>
+" License: MIT
+"   Copyright (c) 2016 rhysd
+"
+" PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+" Generated by script vim-color-spring-night/gen/src/airline.rs
+
+let g:airline#themes#spring_morning#palette = {}
+
+let g:airline#themes#spring_morning#palette.accents = {
+\   'red': ['#cf3f48', '', 167, '', ''],
+\ }
+
+let g:airline#themes#spring_morning#palette.normal = {
+\   'airline_a': ['#fbf8ef', '#ad7a00', 231, 136, ''],
+\   'airline_b': ['#ad7a00', '#c3ccd6', 136, 251, ''],
+\   'airline_c': ['#877200', '#e2dbc9', 94, 253, ''],
+\   'airline_x': ['#877200', '#e2dbc9', 94, 253, ''],
+\   'airline_y': ['#ad7a00', '#c3ccd6', 136, 251, ''],
+\   'airline_z': ['#fbf8ef', '#ad7a00', 231, 136, ''],
+\   'airline_error': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_warning': ['#fbf8ef', '#d65f2e', 231, 166, ''],
+\ }
+let g:airline#themes#spring_morning#palette.normal_modified = {
+\   'airline_a': ['#fbf8ef', '#4c8a3a', 231, 71, ''],
+\   'airline_b': ['#4c8a3a', '#c3ccd6', 71, 251, ''],
+\   'airline_c': ['#5b6a12', '#e2dbc9', 58, 253, ''],
+\   'airline_error': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_warning': ['#fbf8ef', '#d65f2e', 231, 166, ''],
+\ }
+
+let g:airline#themes#spring_morning#palette.insert = {
+\   'airline_a': ['#fbf8ef', '#2d7db0', 231, 67, ''],
+\   'airline_b': ['#2d7db0', '#c3ccd6', 67, 251, ''],
+\   'airline_c': ['#2d4e72', '#e2dbc9', 24, 253, ''],
+\   'airline_x': ['#2d4e72', '#e2dbc9', 24, 253, ''],
+\   'airline_y': ['#2d7db0', '#c3ccd6', 67, 251, ''],
+\   'airline_z': ['#fbf8ef', '#2d7db0', 231, 67, ''],
+\   'airline_error': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_warning': ['#fbf8ef', '#d65f2e', 231, 166, ''],
+\ }
+
+let g:airline#themes#spring_morning#palette.visual = {
+\   'airline_a': ['#fbf8ef', '#c25b78', 231, 168, ''],
+\   'airline_b': ['#c25b78', '#c3ccd6', 168, 251, ''],
+\   'airline_c': ['#7c4a5b', '#e2dbc9', 95, 253, ''],
+\   'airline_x': ['#7c4a5b', '#e2dbc9', 95, 253, ''],
+\   'airline_y': ['#c25b78', '#c3ccd6', 168, 251, ''],
+\   'airline_z': ['#fbf8ef', '#c25b78', 231, 168, ''],
+\   'airline_error': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_warning': ['#fbf8ef', '#d65f2e', 231, 166, ''],
+\ }
+let g:airline#themes#spring_morning#palette.visual_modified = {
+\   'airline_a': ['#fbf8ef', '#e4aabb', 231, 181, ''],
+\   'airline_b': ['#e4aabb', '#c3ccd6', 181, 251, ''],
+\   'airline_c': ['#e4aabb', '#e2dbc9', 181, 253, ''],
+\   'airline_error': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_warning': ['#fbf8ef', '#d65f2e', 231, 166, ''],
+\ }
+
+let g:airline#themes#spring_morning#palette.replace = {
+\   'airline_a': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_b': ['#cf3f48', '#c3ccd6', 167, 251, ''],
+\   'airline_c': ['#8d392b', '#e2dbc9', 52, 253, ''],
+\   'airline_x': ['#8d392b', '#e2dbc9', 52, 253, ''],
+\   'airline_y': ['#cf3f48', '#c3ccd6', 167, 251, ''],
+\   'airline_z': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_error': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_warning': ['#fbf8ef', '#d65f2e', 231, 166, ''],
+\ }
+let g:airline#themes#spring_morning#palette.replace_modified = {
+\   'airline_a': ['#fbf8ef', '#c2242e', 231, 124, ''],
+\   'airline_b': ['#c2242e', '#c3ccd6', 124, 251, ''],
+\   'airline_c': ['#c2242e', '#e2dbc9', 124, 253, ''],
+\   'airline_error': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_warning': ['#fbf8ef', '#d65f2e', 231, 166, ''],
+\ }
+
+let g:airline#themes#spring_morning#palette.inactive = {
+\   'airline_a': ['#707d8b', '#e2dbc9', 244, 253, ''],
+\   'airline_b': ['#707d8b', '#e2dbc9', 244, 253, ''],
+\   'airline_c': ['#707d8b', '#e2dbc9', 244, 253, ''],
+\   'airline_x': ['#707d8b', '#e2dbc9', 244, 253, ''],
+\   'airline_y': ['#707d8b', '#e2dbc9', 244, 253, ''],
+\   'airline_z': ['#707d8b', '#e2dbc9', 244, 253, ''],
+\   'airline_error': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_warning': ['#fbf8ef', '#d65f2e', 231, 166, ''],
+\ }
+
+let g:airline#themes#spring_morning#palette.insert_paste = {
+\   'airline_a': ['#fbf8ef', '#d65f2e', 231, 166, ''],
+\   'airline_b': ['#d65f2e', '#c3ccd6', 166, 251, ''],
+\   'airline_c': ['#d65f2e', '#e2dbc9', 166, 253, ''],
+\   'airline_error': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_warning': ['#fbf8ef', '#d65f2e', 231, 166, ''],
+\ }
+
+let g:airline#themes#spring_morning#palette.inactive_modified = {
+\   'airline_c': ['#4c8a3a', '', 71, '', ''],
+\   'airline_error': ['#fbf8ef', '#cf3f48', 231, 167, ''],
+\   'airline_warning': ['#fbf8ef', '#d65f2e', 231, 166, ''],
+\ }
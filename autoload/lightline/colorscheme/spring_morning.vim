@@ -0,0 +1,39 @@
+" lightline colorscheme for spring-morning colorscheme
+"
+" Author: rhysd <lin90162@yahoo.co.jp>
+" License: MIT
+"   Copyright (c) 2016 rhysd
+"
+" PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+" Generated by script vim-color-spring-night/gen/src/lightline.rs
+
+let s:p = {'normal': {}, 'insert': {}, 'visual': {}, 'replace': {}, 'inactive': {}, 'terminal': {}, 'command': {}, 'tabline': {}}
+let s:p.normal.left = [['#fbf8ef', '#ad7a00', 231, 136], ['#ad7a00', '#c3ccd6', 136, 251]]
+let s:p.normal.middle = [['#877200', '#e2dbc9', 94, 253]]
+let s:p.normal.right = [['#fbf8ef', '#ad7a00', 231, 136], ['#ad7a00', '#c3ccd6', 136, 251]]
+let s:p.insert.left = [['#fbf8ef', '#2d7db0', 231, 67], ['#2d7db0', '#c3ccd6', 67, 251]]
+let s:p.insert.middle = [['#2d4e72', '#e2dbc9', 24, 253]]
+let s:p.insert.right = [['#fbf8ef', '#2d7db0', 231, 67], ['#2d7db0', '#c3ccd6', 67, 251]]
+let s:p.visual.left = [['#fbf8ef', '#c25b78', 231, 168], ['#c25b78', '#c3ccd6', 168, 251]]
+let s:p.visual.middle = [['#7c4a5b', '#e2dbc9', 95, 253]]
+let s:p.visual.right = [['#fbf8ef', '#c25b78', 231, 168], ['#c25b78', '#c3ccd6', 168, 251]]
+let s:p.replace.left = [['#fbf8ef', '#cf3f48', 231, 167], ['#cf3f48', '#c3ccd6', 167, 251]]
+let s:p.replace.middle = [['#8d392b', '#e2dbc9', 52, 253]]
+let s:p.replace.right = [['#fbf8ef', '#cf3f48', 231, 167], ['#cf3f48', '#c3ccd6', 167, 251]]
+let s:p.inactive.left = [['#707d8b', '#e2dbc9', 244, 253], ['#707d8b', '#e2dbc9', 244, 253]]
+let s:p.inactive.middle = [['#707d8b', '#e2dbc9', 244, 253]]
+let s:p.inactive.right = [['#707d8b', '#e2dbc9', 244, 253], ['#707d8b', '#e2dbc9', 244, 253]]
+let s:p.terminal.left = [['#fbf8ef', '#3c66c2', 231, 25], ['#ad7a00', '#c3ccd6', 136, 251]]
+let s:p.terminal.middle = [['#877200', '#e2dbc9', 94, 253]]
+let s:p.terminal.right = [['#fbf8ef', '#3c66c2', 231, 25], ['#ad7a00', '#c3ccd6', 136, 251]]
+let s:p.command.left = [['#fbf8ef', '#ad7a00', 231, 136], ['#ad7a00', '#c3ccd6', 136, 251]]
+let s:p.command.middle = [['#877200', '#e2dbc9', 94, 253]]
+let s:p.command.right = [['#fbf8ef', '#ad7a00', 231, 136], ['#ad7a00', '#c3ccd6', 136, 251]]
+let s:p.normal.error = [['#fbf8ef', '#cf3f48', 231, 167]]
+let s:p.normal.warning = [['#fbf8ef', '#d65f2e', 231, 166]]
+let s:p.tabline.left = [['#ad7a00', '#c3ccd6', 136, 251]]
+let s:p.tabline.tabsel = [['#fbf8ef', '#ad7a00', 231, 136]]
+let s:p.tabline.middle = [['#877200', '#e2dbc9', 94, 253]]
+let s:p.tabline.right = [['#ad7a00', '#c3ccd6', 136, 251]]
+
+let g:lightline#colorscheme#spring_morning#palette = s:p
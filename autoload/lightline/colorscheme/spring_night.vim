@@ -1,61 +1,39 @@
-  " Normal Colors
-  let s:black          = ['#132132', 233]
-  let s:red            = ['#ff6a6f', 203]
-  let s:green          = ['#a9dd9d', 150]
-  let s:yellow         = ['#fedf81', 222]
-  let s:blue           = ['#7098e6', 69]
-  let s:magenta        = ['#605779', 60]
-  let s:cyan           = ['#a8d2eb', 153]
-  let s:white          = ['#fffeeb', 231]
+" lightline colorscheme for spring-night colorscheme
+"
+" Author: rhysd <lin90162@yahoo.co.jp>
+" License: MIT
+"   Copyright (c) 2016 rhysd
+"
+" PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+" Generated by script vim-color-spring-night/gen/src/lightline.rs
 
-  " Bright Colors
-  let s:bright_black   = ['#8d9eb2', 103]
-  let s:bright_red     = ['#fd8489', 210]
-  let s:bright_green   = ['#c9fd88', 149]
-  let s:bright_yellow  = ['#f0eaaa', 229]
-  let s:bright_blue    = ['#98b8e6', 111]
-  let s:bright_magenta = ['#e7d5ff', 189]
-  let s:bright_cyan    = ['#a8d2eb', 153]
-  let s:bright_white   = ['#ffffff', 231]
+let s:p = {'normal': {}, 'insert': {}, 'visual': {}, 'replace': {}, 'inactive': {}, 'terminal': {}, 'command': {}, 'tabline': {}}
+let s:p.normal.left = [['#132132', '#fedf81', 233, 222], ['#fedf81', '#607080', 222, 60]]
+let s:p.normal.middle = [['#f0eaaa', '#435060', 229, 236]]
+let s:p.normal.right = [['#132132', '#fedf81', 233, 222], ['#fedf81', '#607080', 222, 60]]
+let s:p.insert.left = [['#132132', '#a8d2eb', 233, 153], ['#a8d2eb', '#607080', 153, 60]]
+let s:p.insert.middle = [['#d8e2f0', '#435060', 195, 236]]
+let s:p.insert.right = [['#132132', '#a8d2eb', 233, 153], ['#a8d2eb', '#607080', 153, 60]]
+let s:p.visual.left = [['#132132', '#e996aa', 233, 175], ['#e996aa', '#607080', 175, 60]]
+let s:p.visual.middle = [['#ebeadb', '#435060', 224, 236]]
+let s:p.visual.right = [['#132132', '#e996aa', 233, 175], ['#e996aa', '#607080', 175, 60]]
+let s:p.replace.left = [['#132132', '#fd8489', 233, 210], ['#fd8489', '#607080', 210, 60]]
+let s:p.replace.middle = [['#ffbfaf', '#435060', 217, 236]]
+let s:p.replace.right = [['#132132', '#fd8489', 233, 210], ['#fd8489', '#607080', 210, 60]]
+let s:p.inactive.left = [['#8d9eb2', '#435060', 103, 236], ['#8d9eb2', '#435060', 103, 236]]
+let s:p.inactive.middle = [['#8d9eb2', '#435060', 103, 236]]
+let s:p.inactive.right = [['#8d9eb2', '#435060', 103, 236], ['#8d9eb2', '#435060', 103, 236]]
+let s:p.terminal.left = [['#132132', '#7098e6', 233, 69], ['#fedf81', '#607080', 222, 60]]
+let s:p.terminal.middle = [['#f0eaaa', '#435060', 229, 236]]
+let s:p.terminal.right = [['#132132', '#7098e6', 233, 69], ['#fedf81', '#607080', 222, 60]]
+let s:p.command.left = [['#132132', '#fedf81', 233, 222], ['#fedf81', '#607080', 222, 60]]
+let s:p.command.middle = [['#f0eaaa', '#435060', 229, 236]]
+let s:p.command.right = [['#132132', '#fedf81', 233, 222], ['#fedf81', '#607080', 222, 60]]
+let s:p.normal.error = [['#132132', '#fd8489', 233, 210]]
+let s:p.normal.warning = [['#132132', '#fb8965', 233, 209]]
+let s:p.tabline.left = [['#fedf81', '#607080', 222, 60]]
+let s:p.tabline.tabsel = [['#132132', '#fedf81', 233, 222]]
+let s:p.tabline.middle = [['#f0eaaa', '#435060', 229, 236]]
+let s:p.tabline.right = [['#fedf81', '#607080', 222, 60]]
 
-  " Extra Colors
-  let s:orange         = ['#ff8700', 208]
-  let s:bright_orange  = ['#ffaf5f', 215]
-  let s:xgray1         = ['#132132', 238]
-  let s:xgray2         = ['#213243', 239]
-  let s:xgray3         = ['#334152', 240]
-  let s:xgray4         = ['#3a4b5c', 241]
-  let s:xgray5         = ['#536273', 242]
-  let s:xgray6         = ['#646f7c', 243]
-
-  let s:p = {'normal':{}, 'inactive':{}, 'insert':{}, 'replace':{}, 'visual':{}, 'tabline':{}, 'terminal':{}, 'command':{}}
-  let s:p.normal.left     = [ [ s:bright_white, s:xgray5 ], [ s:bright_white, s:xgray3 ] ]
-  let s:p.normal.right    = [ [ s:bright_white, s:xgray5 ], [ s:bright_white, s:xgray3 ] ]
-  let s:p.normal.middle   = [ [ s:bright_white, s:xgray2 ] ]
-  let s:p.inactive.right  = [ [ s:bright_black, s:xgray2 ], [ s:bright_black, s:xgray2 ] ]
-  let s:p.inactive.left   = [ [ s:bright_black, s:xgray2 ], [ s:bright_black, s:xgray2 ] ]
-  let s:p.inactive.middle = [ [ s:xgray6, s:xgray2 ] ]
-  let s:p.insert.left     = [ [ s:black, s:bright_green ], [ s:white, s:xgray5 ] ]
-  let s:p.insert.right    = [ [ s:black, s:bright_green ], [ s:white, s:xgray5 ] ]
-  let s:p.insert.middle   = [ [ s:bright_white, s:xgray2 ] ]
-  let s:p.replace.left    = [ [ s:black, s:red ], [ s:white, s:xgray5 ] ]
-  let s:p.replace.right   = [ [ s:black, s:red ], [ s:white, s:xgray5 ] ]
-  let s:p.replace.middle  = [ [ s:bright_white, s:xgray2 ] ]
-  let s:p.visual.left     = [ [ s:black, s:bright_magenta ], [ s:bright_white, s:xgray5 ] ]
-  let s:p.visual.right    = [ [ s:black, s:bright_magenta ], [ s:bright_white, s:xgray5 ] ]
-  let s:p.visual.middle   = [ [ s:bright_white, s:xgray2 ] ]
-  let s:p.tabline.left    = [ [ s:bright_black, s:xgray2 ] ]
-  let s:p.tabline.tabsel  = [ [ s:bright_white, s:xgray5 ] ]
-  let s:p.tabline.middle  = [ [ s:black, s:xgray2 ] ]
-  let s:p.tabline.right   = [ [ s:bright_white, s:xgray5 ] ]
-  let s:p.normal.error    = [ [ s:bright_white, s:red ] ]
-  let s:p.normal.warning  = [ [ s:black, s:orange ] ]
-  let s:p.terminal.left   = [ [ s:black, s:blue ], [ s:bright_white, s:xgray5 ] ]
-  let s:p.terminal.right  = [ [ s:black, s:blue ], [ s:bright_white, s:xgray5 ] ]
-  let s:p.terminal.middle = [ [ s:bright_white, s:xgray2 ] ]
-  let s:p.command.left    = [ [ s:black, s:yellow ], [ s:bright_white, s:xgray5 ] ]
-  let s:p.command.right   = [ [ s:black, s:yellow ], [ s:bright_white, s:xgray5 ] ]
-  let s:p.command.middle  = [ [ s:bright_white, s:xgray2 ] ]
-
-
-  let g:lightline#colorscheme#spring_night#palette = lightline#colorscheme#flatten(s:p)
+let g:lightline#colorscheme#spring_night#palette = s:p
@@ -0,0 +1,430 @@
+-- spring-morning: Calm-colored light color scheme
+--
+-- Author: rhysd <lin90162@yahoo.co.jp>
+-- License: MIT
+--   Copyright (c) 2016 rhysd
+--
+-- PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+-- Generated by script vim-color-spring-night/gen/src/lua.rs
+
+if vim.o.background ~= 'light' then
+    vim.o.background = 'light'
+end
+
+if vim.g.colors_name then
+    vim.cmd('hi clear')
+end
+
+if vim.g.syntax_on then
+    vim.cmd('syntax reset')
+end
+
+vim.g.colors_name = 'spring-morning'
+
+local function option(name, default)
+    local value = vim.g[name]
+    if value == nil then
+        value = default
+        vim.g[name] = value
+    end
+    return value ~= 0 and value ~= false
+end
+
+local italic_comments = option('spring_night_italic_comments', 0)
+local kill_italic = option('spring_night_kill_italic', 0)
+local kill_bold = option('spring_night_kill_bold', 0)
+local highlight_terminal = option('spring_night_highlight_terminal', 1)
+local cterm_italic = option('spring_night_cterm_italic', 0)
+
+local gui_running = vim.fn.has('gui_running') == 1
+local true_colors = vim.o.termguicolors
+local undercurl = gui_running
+local fancy_underline = gui_running
+local italic = not kill_italic
+local bold = not kill_bold
+cterm_italic = italic and cterm_italic
+
+if vim.g.spring_night_high_contrast == nil then
+    vim.g.spring_night_high_contrast = (not gui_running and true_colors) and 1 or 0
+end
+local high_contrast = option('spring_night_high_contrast', 0)
+
+local hl = vim.api.nvim_set_hl
+
+local bg_gui = high_contrast and '#fbf8ef' or '#f3eee0'
+local bgweaker_gui = high_contrast and '#f1ece0' or '#e8e2d2'
+local darkgold_gui = high_contrast and '#f1e4ad' or '#ebdd9f'
+local fg_cterm = high_contrast and 236 or 238
+
+hl(0, 'Boolean', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, 'Character', { fg = '#4c8a3a', ctermfg = 71 })
+hl(0, 'ColorColumn', { bg = '#d6cfbb', ctermbg = 187 })
+hl(0, 'Comment', { fg = '#707d8b', ctermfg = 244, italic = italic_comments and italic, cterm = { italic = italic_comments and cterm_italic } })
+hl(0, 'Conceal', { fg = '#d65f2e', ctermfg = 166, bg = bg_gui, ctermbg = 231 })
+hl(0, 'Conditional', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'Constant', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, 'Cursor', { fg = bg_gui, ctermfg = 231, bg = '#2a3644', ctermbg = fg_cterm })
+hl(0, 'lCursor', { link = 'Cursor' })
+hl(0, 'CursorColumn', { bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'CursorLine', { bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'CursorLineNr', { fg = '#7a4eaf', ctermfg = 97, bg = '#d6cfbb', ctermbg = 187 })
+hl(0, 'Define', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, 'Directory', { fg = '#4c8a3a', ctermfg = 71 })
+hl(0, 'EndOfBuffer', { fg = '#d6cfbb', ctermfg = 187 })
+hl(0, 'Error', { fg = '#cf3f48', ctermfg = 167, bg = '#ebe5d6', ctermbg = 254, bold = bold, cterm = { bold = bold } })
+hl(0, 'ErrorMsg', { fg = '#cf3f48', ctermfg = 167, bg = bg_gui, ctermbg = 231, bold = bold, cterm = { bold = bold } })
+hl(0, 'Float', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, 'NormalFloat', { fg = '#2a3644', ctermfg = fg_cterm, bg = bgweaker_gui, ctermbg = 255 })
+hl(0, 'FloatBorder', { fg = '#707d8b', ctermfg = 244, bg = bgweaker_gui, ctermbg = 255 })
+hl(0, 'FoldColumn', { fg = '#7a4eaf', ctermfg = 97, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'Folded', { fg = '#7a4eaf', ctermfg = 97, bg = '#b3ac9c', ctermbg = 145 })
+hl(0, 'Function', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, 'Identifier', { fg = '#ad7a00', ctermfg = 136, italic = italic, cterm = { italic = cterm_italic } })
+hl(0, 'IncSearch', { bg = '#e4aabb', ctermbg = 181, underline = true, cterm = { underline = true } })
+hl(0, 'Keyword', { fg = '#877200', ctermfg = 94, bold = bold, cterm = { bold = bold } })
+hl(0, 'Label', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'LineNr', { fg = '#939ba3', ctermfg = 247, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'MatchParen', { fg = bg_gui, ctermfg = 231, bg = '#ad7a00', ctermbg = 136, bold = bold, cterm = { bold = bold } })
+hl(0, 'ModeMsg', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, 'MoreMsg', { fg = '#4c8a3a', ctermfg = 71 })
+hl(0, 'NonText', { fg = '#b3ac9c', ctermfg = 145 })
+hl(0, 'Normal', { fg = '#2a3644', ctermfg = fg_cterm, bg = bg_gui, ctermbg = 231 })
+hl(0, 'Number', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, 'Operator', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, 'Pmenu', { fg = '#7a4eaf', ctermfg = 97, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'PmenuSbar', { fg = '#ad7a00', ctermfg = 136, bg = '#d6cfbb', ctermbg = 187 })
+hl(0, 'PmenuSel', { fg = '#ad7a00', ctermfg = 136, bg = '#d6cfbb', ctermbg = 187 })
+hl(0, 'PmenuThumb', { fg = '#ad7a00', ctermfg = 136, bg = '#707d8b', ctermbg = 244 })
+hl(0, 'PreProc', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, 'Question', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'Search', { bg = '#ddd2ef', ctermbg = 189, underline = true, cterm = { underline = true } })
+hl(0, 'SignColumn', { fg = '#2a3644', ctermfg = fg_cterm, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'Special', { fg = '#877200', ctermfg = 94, bold = bold, cterm = { bold = bold } })
+hl(0, 'SpecialKey', { fg = '#c3ccd6', ctermfg = 251 })
+hl(0, 'SpecialComment', { fg = '#a3695b', ctermfg = 131 })
+if gui_running then
+    hl(0, 'SpellBad', { fg = '#cf3f48', ctermfg = 167, sp = '#cf3f48', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+else
+    hl(0, 'SpellBad', { fg = '#cf3f48', ctermfg = 167, sp = '#cf3f48', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+end
+if gui_running then
+    hl(0, 'SpellCap', { fg = '#7a4eaf', ctermfg = 97, sp = '#7a4eaf', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+else
+    hl(0, 'SpellCap', { fg = '#7a4eaf', ctermfg = 97, sp = '#7a4eaf', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+end
+if gui_running then
+    hl(0, 'SpellLocal', { fg = '#cf3f48', ctermfg = 167, sp = '#cf3f48', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+else
+    hl(0, 'SpellLocal', { fg = '#cf3f48', ctermfg = 167, sp = '#cf3f48', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+end
+if gui_running then
+    hl(0, 'SpellRare', { fg = '#877200', ctermfg = 94, sp = '#877200', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+else
+    hl(0, 'SpellRare', { fg = '#877200', ctermfg = 94, sp = '#877200', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+end
+hl(0, 'Statement', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'StatusLine', { fg = '#2a3644', ctermfg = fg_cterm, bg = '#d6cfbb', ctermbg = 187, bold = bold, cterm = { bold = bold } })
+hl(0, 'StatusLineNC', { fg = '#707d8b', ctermfg = 244, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'StatusLineTerm', { link = 'StatusLine' })
+hl(0, 'StatusLineTermNC', { link = 'StatusLineNC' })
+hl(0, 'StorageClass', { fg = '#ad7a00', ctermfg = 136, italic = italic, cterm = { italic = cterm_italic } })
+hl(0, 'String', { fg = '#4c8a3a', ctermfg = 71 })
+hl(0, 'TabLine', { fg = '#707d8b', ctermfg = 244, bg = '#d6cfbb', ctermbg = 187 })
+hl(0, 'TabLineFill', { fg = '#ebe5d6', ctermfg = 254 })
+hl(0, 'TabLineSel', { fg = '#ad7a00', ctermfg = 136, bg = bg_gui, ctermbg = 231, bold = bold, cterm = { bold = bold } })
+hl(0, 'Tag', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, 'Title', { fg = '#ad7a00', ctermfg = 136, bold = bold, cterm = { bold = bold } })
+hl(0, 'Todo', { fg = bg_gui, ctermfg = 231, bg = '#cf3f48', ctermbg = 167, bold = bold, cterm = { bold = bold } })
+hl(0, 'ToolbarButton', { fg = '#ad7a00', ctermfg = 136, bg = bg_gui, ctermbg = 231, bold = bold, cterm = { bold = bold } })
+hl(0, 'ToolbarLine', { fg = '#707d8b', ctermfg = 244, bg = '#d6cfbb', ctermbg = 187 })
+hl(0, 'Type', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, 'Underlined', { fg = '#2d7db0', ctermfg = 67, underline = true, cterm = { underline = true } })
+hl(0, 'VertSplit', { fg = '#ebe5d6', ctermfg = 254, bg = bg_gui, ctermbg = 231 })
+hl(0, 'Visual', { bg = '#f2d4de', ctermbg = 224 })
+hl(0, 'WarningMsg', { fg = '#d65f2e', ctermfg = 166, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'WildMenu', { fg = bg_gui, ctermfg = 231, bg = '#ad7a00', ctermbg = 136 })
+hl(0, 'cmakeArguments', { fg = '#877200', ctermfg = 94 })
+hl(0, 'cmakeOperators', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, 'cStorageClass', { fg = '#877200', ctermfg = 94 })
+hl(0, 'cTypedef', { fg = '#877200', ctermfg = 94 })
+hl(0, 'DiffAdd', { bg = '#c5e3bf', ctermbg = 151, bold = bold, cterm = { bold = bold } })
+hl(0, 'DiffChange', { bg = darkgold_gui, ctermbg = 187, bold = bold, cterm = { bold = bold } })
+hl(0, 'DiffDelete', { fg = '#2a3644', ctermfg = fg_cterm, bg = '#e6a6a0', ctermbg = 217, bold = bold, cterm = { bold = bold } })
+hl(0, 'DiffText', { bg = bg_gui, ctermbg = 231 })
+hl(0, 'diffAdded', { fg = '#4c8a3a', ctermfg = 71 })
+hl(0, 'diffFile', { fg = '#877200', ctermfg = 94 })
+hl(0, 'diffIndexLine', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, 'diffNewFile', { fg = '#877200', ctermfg = 94 })
+hl(0, 'diffRemoved', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, 'gitcommitOverflow', { bg = '#e6a6a0', ctermbg = 217 })
+hl(0, 'gitcommitSummary', { fg = '#877200', ctermfg = 94 })
+hl(0, 'gitcommitSelectedFile', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'gitconfigSection', { fg = '#2d7db0', ctermfg = 67, bold = bold, cterm = { bold = bold } })
+hl(0, 'glslQualifier', { fg = '#877200', ctermfg = 94 })
+hl(0, 'goBuiltins', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, 'helpExample', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'helpCommand', { fg = '#7a4eaf', ctermfg = 97 })
+hl(0, 'htmlBold', { bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'htmlLinkText', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'htmlTagName', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, 'javaScriptBraces', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, 'makeCommands', { fg = '#877200', ctermfg = 94 })
+hl(0, 'manFooter', { fg = '#4c8a3a', ctermfg = 71 })
+hl(0, 'manHeader', { fg = '#4c8a3a', ctermfg = 71 })
+hl(0, 'manLongOptionDesc', { fg = '#877200', ctermfg = 94 })
+hl(0, 'manOptionDesc', { fg = '#877200', ctermfg = 94 })
+hl(0, 'manReference', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'manSectionHeading', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, 'manSubHeading', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, 'markdownCode', { fg = '#877200', ctermfg = 94 })
+hl(0, 'markdownUrl', { fg = '#707d8b', ctermfg = 244 })
+hl(0, 'ocamlConstructor', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, 'ocamlKeyChar', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'ocamlKeyword', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, 'ocamlFunDef', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'plantumlColonLine', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'pythonBuiltin', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, 'qfFileName', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, 'qfLineNr', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'rstEmphasis', { bg = '#ebe5d6', ctermbg = 254, italic = italic, cterm = { italic = cterm_italic } })
+hl(0, 'rstStrongEmphasis', { bg = '#d6cfbb', ctermbg = 187, bold = bold, cterm = { bold = bold } })
+hl(0, 'rubyFunction', { fg = '#877200', ctermfg = 94 })
+hl(0, 'rubyIdentifier', { fg = '#877200', ctermfg = 94 })
+hl(0, 'rustEnumVariant', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, 'rustFuncCall', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, 'rustCommentLineDoc', { fg = '#a3695b', ctermfg = 131 })
+hl(0, 'scalaInstanceDeclaration', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, 'tomlTable', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'tomlTableArray', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'tomlKey', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, 'tmuxCommands', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'tmuxFlags', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, 'tmuxFormatString', { fg = '#877200', ctermfg = 94 })
+hl(0, 'typescriptBraces', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, 'typescriptAsyncFuncKeyword', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'typescriptKeywordOp', { fg = '#877200', ctermfg = 94, bold = bold, cterm = { bold = bold } })
+hl(0, 'vimfilerColumn__SizeLine', { fg = '#707d8b', ctermfg = 244 })
+hl(0, 'vimfilerClosedFile', { fg = '#4c8a3a', ctermfg = 71 })
+hl(0, 'vimCommand', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'watListDelimiter', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, 'watInstGeneral', { fg = '#877200', ctermfg = 94 })
+hl(0, 'watInstGetSet', { fg = '#877200', ctermfg = 94 })
+hl(0, 'watInstWithType', { fg = '#877200', ctermfg = 94 })
+hl(0, 'watUnnamedVar', { fg = '#7a4eaf', ctermfg = 97 })
+hl(0, 'zshDelimiter', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'zshPrecommand', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, 'debugPC', { fg = bg_gui, ctermfg = 231, bg = '#2d7db0', ctermbg = 67 })
+hl(0, 'debugBreakpoint', { fg = bg_gui, ctermfg = 231, bg = '#ad7a00', ctermbg = 136 })
+hl(0, 'zigMultilineStringDelimiter', { fg = '#877200', ctermfg = 94 })
+hl(0, 'ALEWarningSign', { link = 'DiagnosticSignWarn' })
+hl(0, 'ALEErrorSign', { link = 'DiagnosticSignError' })
+hl(0, 'ALEInfoSign', { bg = '#b3ac9c', ctermbg = 145 })
+hl(0, 'ALEError', { link = 'DiagnosticUnderlineError' })
+hl(0, 'ALEWarning', { link = 'DiagnosticUnderlineWarn' })
+hl(0, 'Flake8_Error', { fg = '#cf3f48', ctermfg = 167, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'Flake8_Warning', { fg = '#877200', ctermfg = 94, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'Flake8_PyFlake', { fg = '#2d7db0', ctermfg = 67, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'Flake8_Complexity', { fg = '#2d7db0', ctermfg = 67, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'Flake8_Naming', { fg = '#2d7db0', ctermfg = 67, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'SignifySignAdd', { fg = '#4c8a3a', ctermfg = 71, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'SignifySignChange', { fg = '#877200', ctermfg = 94, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'SignifySignChangeDelete', { fg = '#ad7a00', ctermfg = 136, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'SignifySignDelete', { fg = '#cf3f48', ctermfg = 167, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'CleverFChar', { fg = bg_gui, ctermfg = 231, bg = '#cf3f48', ctermbg = 167 })
+hl(0, 'CleverFDirect', { fg = bg_gui, ctermfg = 231, bg = '#cf3f48', ctermbg = 167 })
+hl(0, 'DirvishArg', { fg = '#877200', ctermfg = 94, bold = bold, cterm = { bold = bold } })
+hl(0, 'EasyMotionTarget', { fg = '#cf3f48', ctermfg = 167, bold = bold, cterm = { bold = bold } })
+hl(0, 'EasyMotionShade', { fg = '#707d8b', ctermfg = 244, bg = bg_gui, ctermbg = 231 })
+hl(0, 'GitGutterAdd', { link = 'SignifySignAdd' })
+hl(0, 'GitGutterChange', { link = 'SignifySignChange' })
+hl(0, 'GitGutterChangeDelete', { link = 'SignifySignChangeDelete' })
+hl(0, 'GitGutterDelete', { link = 'SignifySignDelete' })
+hl(0, 'HighlightedyankRegion', { bg = '#ebe5d6', ctermbg = 254 })
+if gui_running then
+    hl(0, 'EasyMotionIncCursor', { fg = bg_gui, ctermfg = 231, bg = '#2a3644', ctermbg = fg_cterm })
+else
+    hl(0, 'EasyMotionIncCursor', { reverse = true, cterm = { reverse = true } })
+end
+hl(0, 'plugDeleted', { fg = '#707d8b', ctermfg = 244 })
+hl(0, 'ConflictMarker', { bg = '#e6a6a0', ctermbg = 217 })
+hl(0, 'IndentGuidesOdd', { bg = bgweaker_gui, ctermbg = 255 })
+hl(0, 'IndentGuidesEven', { bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'DiagnosticError', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, 'DiagnosticWarn', { fg = '#d65f2e', ctermfg = 166 })
+hl(0, 'DiagnosticInfo', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, 'DiagnosticHint', { fg = '#707d8b', ctermfg = 244 })
+hl(0, 'DiagnosticOk', { fg = '#4c8a3a', ctermfg = 71 })
+hl(0, 'DiagnosticVirtualTextError', { link = 'DiagnosticError' })
+hl(0, 'DiagnosticVirtualTextWarn', { link = 'DiagnosticWarn' })
+hl(0, 'DiagnosticVirtualTextInfo', { link = 'DiagnosticInfo' })
+hl(0, 'DiagnosticVirtualTextHint', { link = 'DiagnosticHint' })
+hl(0, 'DiagnosticVirtualTextOk', { link = 'DiagnosticOk' })
+hl(0, 'DiagnosticUnderlineError', { bg = '#e6a6a0', ctermbg = 217 })
+hl(0, 'DiagnosticUnderlineWarn', { bg = darkgold_gui, ctermbg = 187 })
+hl(0, 'DiagnosticUnderlineInfo', { sp = '#2d7db0', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+hl(0, 'DiagnosticUnderlineHint', { sp = '#707d8b', underdashed = fancy_underline, underline = not fancy_underline, cterm = { underdashed = fancy_underline, underline = not fancy_underline } })
+hl(0, 'DiagnosticUnderlineOk', { sp = '#4c8a3a', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+hl(0, 'DiagnosticSignError', { fg = '#ebe5d6', ctermfg = 254, bg = '#e6a6a0', ctermbg = 217, bold = bold, cterm = { bold = bold } })
+hl(0, 'DiagnosticSignWarn', { fg = '#bf6a3c', ctermfg = 173, bg = '#ebe5d6', ctermbg = 254, bold = bold, cterm = { bold = bold } })
+hl(0, 'DiagnosticSignInfo', { fg = '#2d7db0', ctermfg = 67, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'DiagnosticSignHint', { fg = '#707d8b', ctermfg = 244, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'DiagnosticSignOk', { fg = '#4c8a3a', ctermfg = 71, bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'DiagnosticFloatingError', { fg = '#cf3f48', ctermfg = 167, bg = bgweaker_gui, ctermbg = 255 })
+hl(0, 'DiagnosticFloatingWarn', { fg = '#d65f2e', ctermfg = 166, bg = bgweaker_gui, ctermbg = 255 })
+hl(0, 'DiagnosticFloatingInfo', { fg = '#2d7db0', ctermfg = 67, bg = bgweaker_gui, ctermbg = 255 })
+hl(0, 'DiagnosticFloatingHint', { fg = '#707d8b', ctermfg = 244, bg = bgweaker_gui, ctermbg = 255 })
+hl(0, 'DiagnosticFloatingOk', { fg = '#4c8a3a', ctermfg = 71, bg = bgweaker_gui, ctermbg = 255 })
+hl(0, 'DiagnosticUnnecessary', { fg = '#707d8b', ctermfg = 244 })
+hl(0, 'DiagnosticDeprecated', { fg = '#707d8b', ctermfg = 244, strikethrough = true, cterm = { strikethrough = true } })
+hl(0, 'LspReferenceText', { bg = '#ebe5d6', ctermbg = 254 })
+hl(0, 'LspReferenceRead', { link = 'LspReferenceText' })
+hl(0, 'LspReferenceWrite', { bg = '#d6cfbb', ctermbg = 187 })
+hl(0, 'LspInlayHint', { fg = '#939ba3', ctermfg = 247, bg = bgweaker_gui, ctermbg = 255 })
+hl(0, 'LspCodeLens', { fg = '#939ba3', ctermfg = 247 })
+hl(0, 'LspCodeLensSeparator', { fg = '#c3ccd6', ctermfg = 251 })
+hl(0, 'LspSignatureActiveParameter', { fg = '#ad7a00', ctermfg = 136, bold = bold, cterm = { bold = bold } })
+hl(0, '@lsp.type.class', { link = '@type' })
+hl(0, '@lsp.type.comment', { link = '@comment' })
+hl(0, '@lsp.type.decorator', { link = '@attribute' })
+hl(0, '@lsp.type.enum', { link = '@type' })
+hl(0, '@lsp.type.enumMember', { link = '@constructor' })
+hl(0, '@lsp.type.function', { link = '@function' })
+hl(0, '@lsp.type.interface', { link = '@type' })
+hl(0, '@lsp.type.keyword', { link = '@keyword' })
+hl(0, '@lsp.type.macro', { link = '@function.macro' })
+hl(0, '@lsp.type.method', { link = '@function.method' })
+hl(0, '@lsp.type.namespace', { link = '@module' })
+hl(0, '@lsp.type.number', { link = '@number' })
+hl(0, '@lsp.type.operator', { link = '@operator' })
+hl(0, '@lsp.type.parameter', { link = '@variable.parameter' })
+hl(0, '@lsp.type.property', { link = '@property' })
+hl(0, '@lsp.type.string', { link = '@string' })
+hl(0, '@lsp.type.struct', { link = '@type' })
+hl(0, '@lsp.type.type', { link = '@type' })
+hl(0, '@lsp.type.typeParameter', { link = '@type.definition' })
+hl(0, '@lsp.type.variable', { link = '@variable' })
+hl(0, '@lsp.mod.deprecated', { fg = '#707d8b', ctermfg = 244, strikethrough = true, cterm = { strikethrough = true } })
+hl(0, '@lsp.typemod.function.defaultLibrary', { link = '@function.builtin' })
+hl(0, '@lsp.typemod.variable.defaultLibrary', { link = '@variable.builtin' })
+hl(0, '@variable', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, '@variable.builtin', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, '@variable.parameter', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, '@variable.member', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, '@constant', { link = 'Constant' })
+hl(0, '@constant.builtin', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, '@constant.macro', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, '@module', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, '@module.builtin', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, '@label', { link = 'Label' })
+hl(0, '@string', { link = 'String' })
+hl(0, '@string.documentation', { fg = '#a3695b', ctermfg = 131 })
+hl(0, '@string.regexp', { fg = '#877200', ctermfg = 94 })
+hl(0, '@string.escape', { fg = '#877200', ctermfg = 94, bold = bold, cterm = { bold = bold } })
+hl(0, '@string.special', { fg = '#877200', ctermfg = 94 })
+hl(0, '@string.special.symbol', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, '@string.special.url', { fg = '#2d7db0', ctermfg = 67, underline = true, cterm = { underline = true } })
+hl(0, '@character', { link = 'Character' })
+hl(0, '@character.special', { fg = '#877200', ctermfg = 94, bold = bold, cterm = { bold = bold } })
+hl(0, '@boolean', { link = 'Boolean' })
+hl(0, '@number', { link = 'Number' })
+hl(0, '@number.float', { link = 'Float' })
+hl(0, '@type', { link = 'Type' })
+hl(0, '@type.builtin', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, '@type.definition', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, '@attribute', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, '@attribute.builtin', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, '@property', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, '@function', { link = 'Function' })
+hl(0, '@function.builtin', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, '@function.call', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, '@function.macro', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, '@function.method', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, '@function.method.call', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, '@constructor', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, '@operator', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, '@keyword', { link = 'Keyword' })
+hl(0, '@keyword.coroutine', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, '@keyword.function', { fg = '#877200', ctermfg = 94, bold = bold, cterm = { bold = bold } })
+hl(0, '@keyword.operator', { fg = '#877200', ctermfg = 94, bold = bold, cterm = { bold = bold } })
+hl(0, '@keyword.import', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, '@keyword.type', { fg = '#ad7a00', ctermfg = 136, italic = italic, cterm = { italic = cterm_italic } })
+hl(0, '@keyword.modifier', { fg = '#ad7a00', ctermfg = 136, italic = italic, cterm = { italic = cterm_italic } })
+hl(0, '@keyword.repeat', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, '@keyword.return', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, '@keyword.debug', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, '@keyword.exception', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, '@keyword.conditional', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, '@keyword.conditional.ternary', { link = '@operator' })
+hl(0, '@keyword.directive', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, '@keyword.directive.define', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, '@punctuation.delimiter', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, '@punctuation.bracket', { fg = '#2a3644', ctermfg = fg_cterm })
+hl(0, '@punctuation.special', { fg = '#877200', ctermfg = 94, bold = bold, cterm = { bold = bold } })
+hl(0, '@comment', { link = 'Comment' })
+hl(0, '@comment.documentation', { fg = '#a3695b', ctermfg = 131 })
+hl(0, '@comment.error', { fg = bg_gui, ctermfg = 231, bg = '#cf3f48', ctermbg = 167, bold = bold, cterm = { bold = bold } })
+hl(0, '@comment.warning', { fg = bg_gui, ctermfg = 231, bg = '#d65f2e', ctermbg = 166, bold = bold, cterm = { bold = bold } })
+hl(0, '@comment.todo', { fg = bg_gui, ctermfg = 231, bg = '#cf3f48', ctermbg = 167, bold = bold, cterm = { bold = bold } })
+hl(0, '@comment.note', { fg = bg_gui, ctermfg = 231, bg = '#2d7db0', ctermbg = 67, bold = bold, cterm = { bold = bold } })
+hl(0, '@markup.strong', { bold = bold, cterm = { bold = bold } })
+hl(0, '@markup.italic', { italic = italic, cterm = { italic = cterm_italic } })
+hl(0, '@markup.underline', { underline = true, cterm = { underline = true } })
+hl(0, '@markup.strikethrough', { strikethrough = true, cterm = { strikethrough = true } })
+hl(0, '@markup.heading', { fg = '#ad7a00', ctermfg = 136, bold = bold, cterm = { bold = bold } })
+hl(0, '@markup.quote', { fg = '#707d8b', ctermfg = 244 })
+hl(0, '@markup.math', { fg = '#877200', ctermfg = 94 })
+hl(0, '@markup.link', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, '@markup.link.label', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, '@markup.link.url', { fg = '#707d8b', ctermfg = 244, underline = true, cterm = { underline = true } })
+hl(0, '@markup.raw', { fg = '#877200', ctermfg = 94 })
+hl(0, '@markup.raw.block', { fg = '#877200', ctermfg = 94 })
+hl(0, '@markup.list', { fg = '#2d7db0', ctermfg = 67 })
+hl(0, '@markup.list.checked', { fg = '#4c8a3a', ctermfg = 71 })
+hl(0, '@markup.list.unchecked', { fg = '#707d8b', ctermfg = 244 })
+hl(0, '@diff.plus', { fg = '#4c8a3a', ctermfg = 71 })
+hl(0, '@diff.minus', { fg = '#cf3f48', ctermfg = 167 })
+hl(0, '@diff.delta', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, '@tag', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, '@tag.builtin', { fg = '#bf6a3c', ctermfg = 173 })
+hl(0, '@tag.attribute', { fg = '#ad7a00', ctermfg = 136 })
+hl(0, '@tag.delimiter', { fg = '#707d8b', ctermfg = 244 })
+
+if highlight_terminal then
+    if gui_running or true_colors then
+        vim.g.terminal_color_0 = '#fbf8ef'
+        vim.g.terminal_color_1 = '#c2242e'
+        vim.g.terminal_color_2 = '#4c8a3a'
+        vim.g.terminal_color_3 = '#ad7a00'
+        vim.g.terminal_color_4 = '#3c66c2'
+        vim.g.terminal_color_5 = '#7a4eaf'
+        vim.g.terminal_color_6 = '#2d7db0'
+        vim.g.terminal_color_7 = '#2a3644'
+        vim.g.terminal_color_8 = '#939ba3'
+        vim.g.terminal_color_9 = '#cf3f48'
+        vim.g.terminal_color_10 = '#5a9a1c'
+        vim.g.terminal_color_11 = '#877200'
+        vim.g.terminal_color_12 = '#5a80c8'
+        vim.g.terminal_color_13 = '#7a4eaf'
+        vim.g.terminal_color_14 = '#3891c0'
+        vim.g.terminal_color_15 = '#fdfdf8'
+    else
+        vim.g.terminal_color_0 = 231
+        vim.g.terminal_color_1 = 124
+        vim.g.terminal_color_2 = 71
+        vim.g.terminal_color_3 = 136
+        vim.g.terminal_color_4 = 25
+        vim.g.terminal_color_5 = 97
+        vim.g.terminal_color_6 = 67
+        vim.g.terminal_color_7 = 236
+        vim.g.terminal_color_8 = 247
+        vim.g.terminal_color_9 = 167
+        vim.g.terminal_color_10 = 64
+        vim.g.terminal_color_11 = 94
+        vim.g.terminal_color_12 = 68
+        vim.g.terminal_color_13 = 97
+        vim.g.terminal_color_14 = 31
+        vim.g.terminal_color_15 = 231
+    end
+    vim.g.terminal_color_background = vim.g.terminal_color_0
+    vim.g.terminal_color_foreground = vim.g.terminal_color_7
+end
@@ -0,0 +1,349 @@
+" spring-morning: Calm-colored light color scheme
+"
+" Author: rhysd <lin90162@yahoo.co.jp>
+" License: MIT
+"   Copyright (c) 2016 rhysd
+"
+" PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+" Generated by script vim-color-spring-night/gen/src/colorscheme.rs
+
+" Neovim loads the Lua version of this colorscheme which sets highlights with
+" nvim_set_hl() instead of building `:hi` commands with `:execute`.
+if has('nvim-0.8')
+    execute 'luafile' fnameescape(expand('<sfile>:p:r') . '.lua')
+    finish
+endif
+
+" Optimization:
+" `:set background=light` has some side effects which takes a time.
+" Avoid the side effects when the value is already 'light'.
+if &background !=# 'light'
+    set background=light
+endif
+
+" Optimization:
+" `:hi clear` takes a lot of time since it clears all highlights and set default
+" highlights. This guard avoids `:hi clear` if spring-morning is the first colorscheme.
+" applied in vimrc. In almost all cases no additional highlights are set at start
+" up since they are set by Vim plugins.
+if exists('g:colors_name')
+    " Remove all existing user-defined highlights and set the defaults.
+    hi clear
+endif
+
+if exists('g:syntax_on')
+    syntax reset
+endif
+
+let g:colors_name = 'spring-morning'
+
+let g:spring_night_italic_comments = get(g:, 'spring_night_italic_comments', 0)
+let g:spring_night_kill_italic = get(g:, 'spring_night_kill_italic', 0)
+let g:spring_night_kill_bold = get(g:, 'spring_night_kill_bold', 0)
+let g:spring_night_highlight_terminal = get(g:, 'spring_night_highlight_terminal', 1)
+let g:spring_night_cterm_italic = get(g:, 'spring_night_cterm_italic', 0)
+
+let s:gui_running = has('gui_running')
+let s:true_colors = has('termguicolors') && &termguicolors
+let s:undercurl_attr = s:gui_running ? 'gui=undercurl cterm=undercurl' : 'gui=underline cterm=underline'
+let s:italic_attr = g:spring_night_kill_italic ? '' : g:spring_night_cterm_italic ? 'gui=italic cterm=italic' : 'gui=italic'
+let s:bold_attr = g:spring_night_kill_bold ? '' : 'gui=bold cterm=bold'
+" underdouble, underdotted and underdashed are available since Vim 9.0
+let s:fancy_underline = s:gui_running && v:version >= 900
+let s:underdouble_attr = s:fancy_underline ? 'gui=underdouble cterm=underdouble' : 'gui=underline cterm=underline'
+let s:underdotted_attr = s:fancy_underline ? 'gui=underdotted cterm=underdotted' : 'gui=underline cterm=underline'
+let s:underdashed_attr = s:fancy_underline ? 'gui=underdashed cterm=underdashed' : 'gui=underline cterm=underline'
+" Attributes for combining them in one `gui=` or `cterm=` argument
+let s:bold = g:spring_night_kill_bold ? '' : 'bold,'
+let s:italic = g:spring_night_kill_italic ? '' : 'italic,'
+let s:cterm_italic = g:spring_night_cterm_italic ? s:italic : ''
+let s:undercurl = s:gui_running ? 'undercurl,' : 'underline,'
+let s:underdouble = s:fancy_underline ? 'underdouble,' : 'underline,'
+let s:underdotted = s:fancy_underline ? 'underdotted,' : 'underline,'
+let s:underdashed = s:fancy_underline ? 'underdashed,' : 'underline,'
+
+if exists('g:spring_night_high_contrast')
+    if type(g:spring_night_high_contrast) != type(0)
+        echoerr 'g:spring_night_high_contrast was changed to number value. Please read README.md of vim-color-spring-night repository and set proper value'
+        let g:spring_night_high_contrast = !s:gui_running && s:true_colors
+    endif
+else
+    let g:spring_night_high_contrast = !s:gui_running && s:true_colors
+endif
+
+let s:bg_gui = g:spring_night_high_contrast ? '#fbf8ef' : '#f3eee0'
+let s:bgweaker_gui = g:spring_night_high_contrast ? '#f1ece0' : '#e8e2d2'
+let s:darkgold_gui = g:spring_night_high_contrast ? '#f1e4ad' : '#ebdd9f'
+let s:fg_cterm = g:spring_night_high_contrast ? 236 : 238
+
+hi Boolean term=NONE guifg=#cf3f48 ctermfg=167
+hi Character term=NONE guifg=#4c8a3a ctermfg=71
+hi ColorColumn term=NONE guibg=#d6cfbb ctermbg=187
+exe 'hi' 'Comment term=NONE' 'guifg=#707d8b' 'ctermfg=244' g:spring_night_italic_comments ? s:italic_attr : ''
+exe 'hi' 'Conceal term=NONE' 'guifg=#d65f2e' 'ctermfg=166' 'guibg='.s:bg_gui 'ctermbg=231'
+hi Conditional term=NONE guifg=#2d7db0 ctermfg=67
+hi Constant term=NONE guifg=#cf3f48 ctermfg=167
+exe 'hi' 'Cursor term=NONE' 'guifg='.s:bg_gui 'ctermfg=231' 'guibg=#2a3644' 'ctermbg='.s:fg_cterm
+hi! link lCursor Cursor
+hi CursorColumn term=NONE guibg=#ebe5d6 ctermbg=254
+hi CursorLine term=NONE guibg=#ebe5d6 ctermbg=254 gui=NONE cterm=NONE
+hi CursorLineNr term=NONE guifg=#7a4eaf ctermfg=97 guibg=#d6cfbb ctermbg=187
+hi Define term=NONE guifg=#bf6a3c ctermfg=173
+hi Directory term=NONE guifg=#4c8a3a ctermfg=71
+hi EndOfBuffer term=NONE guifg=#d6cfbb ctermfg=187
+exe 'hi' 'Error term=NONE' 'guifg=#cf3f48' 'ctermfg=167' 'guibg=#ebe5d6' 'ctermbg=254' s:bold_attr
+exe 'hi' 'ErrorMsg term=NONE' 'guifg=#cf3f48' 'ctermfg=167' 'guibg='.s:bg_gui 'ctermbg=231' s:bold_attr
+hi Float term=NONE guifg=#cf3f48 ctermfg=167
+exe 'hi' 'NormalFloat term=NONE' 'guifg=#2a3644' 'ctermfg='.s:fg_cterm 'guibg='.s:bgweaker_gui 'ctermbg=255'
+exe 'hi' 'FloatBorder term=NONE' 'guifg=#707d8b' 'ctermfg=244' 'guibg='.s:bgweaker_gui 'ctermbg=255'
+hi FoldColumn term=NONE guifg=#7a4eaf ctermfg=97 guibg=#ebe5d6 ctermbg=254
+hi Folded term=NONE guifg=#7a4eaf ctermfg=97 guibg=#b3ac9c ctermbg=145
+hi Function term=NONE guifg=#bf6a3c ctermfg=173
+exe 'hi' 'Identifier term=NONE' 'guifg=#ad7a00' 'ctermfg=136' s:italic_attr
+hi IncSearch term=NONE guifg=NONE ctermfg=NONE guibg=#e4aabb ctermbg=181 gui=underline cterm=underline
+exe 'hi' 'Keyword term=NONE' 'guifg=#877200' 'ctermfg=94' s:bold_attr
+hi Label term=NONE guifg=#2d7db0 ctermfg=67
+hi LineNr term=NONE guifg=#939ba3 ctermfg=247 guibg=#ebe5d6 ctermbg=254
+exe 'hi' 'MatchParen term=NONE' 'guifg='.s:bg_gui 'ctermfg=231' 'guibg=#ad7a00' 'ctermbg=136' s:bold_attr
+hi ModeMsg term=NONE guifg=#ad7a00 ctermfg=136
+hi MoreMsg term=NONE guifg=#4c8a3a ctermfg=71
+hi NonText term=NONE guifg=#b3ac9c ctermfg=145
+exe 'hi' 'Normal term=NONE' 'guifg=#2a3644' 'ctermfg='.s:fg_cterm 'guibg='.s:bg_gui 'ctermbg=231'
+hi Number term=NONE guifg=#cf3f48 ctermfg=167
+hi Operator term=NONE guifg=#bf6a3c ctermfg=173
+hi Pmenu term=NONE guifg=#7a4eaf ctermfg=97 guibg=#ebe5d6 ctermbg=254
+hi PmenuSbar term=NONE guifg=#ad7a00 ctermfg=136 guibg=#d6cfbb ctermbg=187
+hi PmenuSel term=NONE guifg=#ad7a00 ctermfg=136 guibg=#d6cfbb ctermbg=187
+hi PmenuThumb term=NONE guifg=#ad7a00 ctermfg=136 guibg=#707d8b ctermbg=244
+hi PreProc term=NONE guifg=#bf6a3c ctermfg=173
+hi Question term=NONE guifg=#2d7db0 ctermfg=67
+hi Search term=NONE guifg=NONE ctermfg=NONE guibg=#ddd2ef ctermbg=189 gui=underline cterm=underline
+exe 'hi' 'SignColumn term=NONE' 'guifg=#2a3644' 'ctermfg='.s:fg_cterm 'guibg=#ebe5d6' 'ctermbg=254'
+exe 'hi' 'Special term=NONE' 'guifg=#877200' 'ctermfg=94' s:bold_attr
+hi SpecialKey term=NONE guifg=#c3ccd6 ctermfg=251
+hi SpecialComment term=NONE guifg=#a3695b ctermfg=131
+if s:gui_running
+    exe 'hi' 'SpellBad term=NONE' 'guifg=#cf3f48' 'ctermfg=167' 'guisp=#cf3f48' s:undercurl_attr
+else
+    exe 'hi' 'SpellBad term=NONE' 'guifg=#cf3f48' 'ctermfg=167' 'guibg=NONE' 'ctermbg=NONE' 'guisp=#cf3f48' s:undercurl_attr
+endif
+if s:gui_running
+    exe 'hi' 'SpellCap term=NONE' 'guifg=#7a4eaf' 'ctermfg=97' 'guisp=#7a4eaf' s:undercurl_attr
+else
+    exe 'hi' 'SpellCap term=NONE' 'guifg=#7a4eaf' 'ctermfg=97' 'guibg=NONE' 'ctermbg=NONE' 'guisp=#7a4eaf' s:undercurl_attr
+endif
+if s:gui_running
+    exe 'hi' 'SpellLocal term=NONE' 'guifg=#cf3f48' 'ctermfg=167' 'guisp=#cf3f48' s:undercurl_attr
+else
+    exe 'hi' 'SpellLocal term=NONE' 'guifg=#cf3f48' 'ctermfg=167' 'guibg=NONE' 'ctermbg=NONE' 'guisp=#cf3f48' s:undercurl_attr
+endif
+if s:gui_running
+    exe 'hi' 'SpellRare term=NONE' 'guifg=#877200' 'ctermfg=94' 'guisp=#877200' s:undercurl_attr
+else
+    exe 'hi' 'SpellRare term=NONE' 'guifg=#877200' 'ctermfg=94' 'guibg=NONE' 'ctermbg=NONE' 'guisp=#877200' s:undercurl_attr
+endif
+hi Statement term=NONE guifg=#2d7db0 ctermfg=67
+exe 'hi' 'StatusLine term=NONE' 'guifg=#2a3644' 'ctermfg='.s:fg_cterm 'guibg=#d6cfbb' 'ctermbg=187' s:bold_attr
+hi StatusLineNC term=NONE guifg=#707d8b ctermfg=244 guibg=#ebe5d6 ctermbg=254 gui=NONE cterm=NONE
+hi! link StatusLineTerm StatusLine
+hi! link StatusLineTermNC StatusLineNC
+exe 'hi' 'StorageClass term=NONE' 'guifg=#ad7a00' 'ctermfg=136' s:italic_attr
+hi String term=NONE guifg=#4c8a3a ctermfg=71
+hi TabLine term=NONE guifg=#707d8b ctermfg=244 guibg=#d6cfbb ctermbg=187
+hi TabLineFill term=NONE guifg=#ebe5d6 ctermfg=254
+exe 'hi' 'TabLineSel term=NONE' 'guifg=#ad7a00' 'ctermfg=136' 'guibg='.s:bg_gui 'ctermbg=231' s:bold_attr
+hi Tag term=NONE guifg=#bf6a3c ctermfg=173
+exe 'hi' 'Title term=NONE' 'guifg=#ad7a00' 'ctermfg=136' s:bold_attr
+exe 'hi' 'Todo term=NONE' 'guifg='.s:bg_gui 'ctermfg=231' 'guibg=#cf3f48' 'ctermbg=167' s:bold_attr
+exe 'hi' 'ToolbarButton term=NONE' 'guifg=#ad7a00' 'ctermfg=136' 'guibg='.s:bg_gui 'ctermbg=231' s:bold_attr
+hi ToolbarLine term=NONE guifg=#707d8b ctermfg=244 guibg=#d6cfbb ctermbg=187
+hi Type term=NONE guifg=#ad7a00 ctermfg=136
+hi Underlined term=NONE guifg=#2d7db0 ctermfg=67 gui=underline cterm=underline
+exe 'hi' 'VertSplit term=NONE' 'guifg=#ebe5d6' 'ctermfg=254' 'guibg='.s:bg_gui 'ctermbg=231'
+hi Visual term=NONE guibg=#f2d4de ctermbg=224
+hi WarningMsg term=NONE guifg=#d65f2e ctermfg=166 guibg=#ebe5d6 ctermbg=254
+exe 'hi' 'WildMenu term=NONE' 'guifg='.s:bg_gui 'ctermfg=231' 'guibg=#ad7a00' 'ctermbg=136'
+hi cmakeArguments term=NONE guifg=#877200 ctermfg=94
+hi cmakeOperators term=NONE guifg=#cf3f48 ctermfg=167
+hi cStorageClass term=NONE guifg=#877200 ctermfg=94
+hi cTypedef term=NONE guifg=#877200 ctermfg=94
+exe 'hi' 'DiffAdd term=NONE' 'guibg=#c5e3bf' 'ctermbg=151' s:bold_attr
+exe 'hi' 'DiffChange term=NONE' 'guibg='.s:darkgold_gui 'ctermbg=187' s:bold_attr
+exe 'hi' 'DiffDelete term=NONE' 'guifg=#2a3644' 'ctermfg='.s:fg_cterm 'guibg=#e6a6a0' 'ctermbg=217' s:bold_attr
+exe 'hi' 'DiffText term=NONE' 'guibg='.s:bg_gui 'ctermbg=231'
+hi diffAdded term=NONE guifg=#4c8a3a ctermfg=71
+hi diffFile term=NONE guifg=#877200 ctermfg=94
+hi diffIndexLine term=NONE guifg=#ad7a00 ctermfg=136
+hi diffNewFile term=NONE guifg=#877200 ctermfg=94
+hi diffRemoved term=NONE guifg=#cf3f48 ctermfg=167
+hi gitcommitOverflow term=NONE guibg=#e6a6a0 ctermbg=217
+hi gitcommitSummary term=NONE guifg=#877200 ctermfg=94
+hi gitcommitSelectedFile term=NONE guifg=#2d7db0 ctermfg=67
+exe 'hi' 'gitconfigSection term=NONE' 'guifg=#2d7db0' 'ctermfg=67' s:bold_attr
+hi glslQualifier term=NONE guifg=#877200 ctermfg=94
+hi goBuiltins term=NONE guifg=#cf3f48 ctermfg=167
+hi helpExample term=NONE guifg=#2d7db0 ctermfg=67
+hi helpCommand term=NONE guifg=#7a4eaf ctermfg=97
+hi htmlBold term=NONE guibg=#ebe5d6 ctermbg=254
+hi htmlLinkText term=NONE guifg=#2d7db0 ctermfg=67
+hi htmlTagName term=NONE guifg=#bf6a3c ctermfg=173
+exe 'hi' 'javaScriptBraces term=NONE' 'guifg=#2a3644' 'ctermfg='.s:fg_cterm
+hi makeCommands term=NONE guifg=#877200 ctermfg=94
+hi manFooter term=NONE guifg=#4c8a3a ctermfg=71
+hi manHeader term=NONE guifg=#4c8a3a ctermfg=71
+hi manLongOptionDesc term=NONE guifg=#877200 ctermfg=94
+hi manOptionDesc term=NONE guifg=#877200 ctermfg=94
+hi manReference term=NONE guifg=#2d7db0 ctermfg=67
+hi manSectionHeading term=NONE guifg=#ad7a00 ctermfg=136
+hi manSubHeading term=NONE guifg=#bf6a3c ctermfg=173
+hi markdownCode term=NONE guifg=#877200 ctermfg=94
+hi markdownUrl term=NONE guifg=#707d8b ctermfg=244
+hi ocamlConstructor term=NONE guifg=#ad7a00 ctermfg=136
+hi ocamlKeyChar term=NONE guifg=#2d7db0 ctermfg=67
+hi ocamlKeyword term=NONE guifg=#ad7a00 ctermfg=136
+hi ocamlFunDef term=NONE guifg=#2d7db0 ctermfg=67
+hi plantumlColonLine term=NONE guifg=#2d7db0 ctermfg=67
+hi pythonBuiltin term=NONE guifg=#cf3f48 ctermfg=167
+hi qfFileName term=NONE guifg=#ad7a00 ctermfg=136
+hi qfLineNr term=NONE guifg=#2d7db0 ctermfg=67
+exe 'hi' 'rstEmphasis term=NONE' 'guibg=#ebe5d6' 'ctermbg=254' s:italic_attr
+exe 'hi' 'rstStrongEmphasis term=NONE' 'guibg=#d6cfbb' 'ctermbg=187' s:bold_attr
+hi rubyFunction term=NONE guifg=#877200 ctermfg=94
+hi rubyIdentifier term=NONE guifg=#877200 ctermfg=94
+hi rustEnumVariant term=NONE guifg=#ad7a00 ctermfg=136
+exe 'hi' 'rustFuncCall term=NONE' 'guifg=#2a3644' 'ctermfg='.s:fg_cterm
+hi rustCommentLineDoc term=NONE guifg=#a3695b ctermfg=131
+hi scalaInstanceDeclaration term=NONE guifg=#ad7a00 ctermfg=136
+hi tomlTable term=NONE guifg=#2d7db0 ctermfg=67
+hi tomlTableArray term=NONE guifg=#2d7db0 ctermfg=67
+hi tomlKey term=NONE guifg=#ad7a00 ctermfg=136
+hi tmuxCommands term=NONE guifg=#2d7db0 ctermfg=67
+hi tmuxFlags term=NONE guifg=#ad7a00 ctermfg=136
+hi tmuxFormatString term=NONE guifg=#877200 ctermfg=94
+exe 'hi' 'typescriptBraces term=NONE' 'guifg=#2a3644' 'ctermfg='.s:fg_cterm
+hi typescriptAsyncFuncKeyword term=NONE guifg=#2d7db0 ctermfg=67
+exe 'hi' 'typescriptKeywordOp term=NONE' 'guifg=#877200' 'ctermfg=94' s:bold_attr
+hi vimfilerColumn__SizeLine term=NONE guifg=#707d8b ctermfg=244
+hi vimfilerClosedFile term=NONE guifg=#4c8a3a ctermfg=71
+hi vimCommand term=NONE guifg=#2d7db0 ctermfg=67
+exe 'hi' 'watListDelimiter term=NONE' 'guifg=#2a3644' 'ctermfg='.s:fg_cterm
+hi watInstGeneral term=NONE guifg=#877200 ctermfg=94
+hi watInstGetSet term=NONE guifg=#877200 ctermfg=94
+hi watInstWithType term=NONE guifg=#877200 ctermfg=94
+hi watUnnamedVar term=NONE guifg=#7a4eaf ctermfg=97
+hi zshDelimiter term=NONE guifg=#2d7db0 ctermfg=67
+hi zshPrecommand term=NONE guifg=#cf3f48 ctermfg=167
+exe 'hi' 'debugPC term=NONE' 'guifg='.s:bg_gui 'ctermfg=231' 'guibg=#2d7db0' 'ctermbg=67'
+exe 'hi' 'debugBreakpoint term=NONE' 'guifg='.s:bg_gui 'ctermfg=231' 'guibg=#ad7a00' 'ctermbg=136'
+hi zigMultilineStringDelimiter term=NONE guifg=#877200 ctermfg=94
+hi! link ALEWarningSign DiagnosticSignWarn
+hi! link ALEErrorSign DiagnosticSignError
+hi ALEInfoSign term=NONE guibg=#b3ac9c ctermbg=145
+hi! link ALEError DiagnosticUnderlineError
+hi! link ALEWarning DiagnosticUnderlineWarn
+hi Flake8_Error term=NONE guifg=#cf3f48 ctermfg=167 guibg=#ebe5d6 ctermbg=254
+hi Flake8_Warning term=NONE guifg=#877200 ctermfg=94 guibg=#ebe5d6 ctermbg=254
+hi Flake8_PyFlake term=NONE guifg=#2d7db0 ctermfg=67 guibg=#ebe5d6 ctermbg=254
+hi Flake8_Complexity term=NONE guifg=#2d7db0 ctermfg=67 guibg=#ebe5d6 ctermbg=254
+hi Flake8_Naming term=NONE guifg=#2d7db0 ctermfg=67 guibg=#ebe5d6 ctermbg=254
+hi SignifySignAdd term=NONE guifg=#4c8a3a ctermfg=71 guibg=#ebe5d6 ctermbg=254
+hi SignifySignChange term=NONE guifg=#877200 ctermfg=94 guibg=#ebe5d6 ctermbg=254
+hi SignifySignChangeDelete term=NONE guifg=#ad7a00 ctermfg=136 guibg=#ebe5d6 ctermbg=254
+hi SignifySignDelete term=NONE guifg=#cf3f48 ctermfg=167 guibg=#ebe5d6 ctermbg=254
+exe 'hi' 'CleverFChar term=NONE' 'guifg='.s:bg_gui 'ctermfg=231' 'guibg=#cf3f48' 'ctermbg=167'
+exe 'hi' 'CleverFDirect term=NONE' 'guifg='.s:bg_gui 'ctermfg=231' 'guibg=#cf3f48' 'ctermbg=167'
+exe 'hi' 'DirvishArg term=NONE' 'guifg=#877200' 'ctermfg=94' s:bold_attr
+exe 'hi' 'EasyMotionTarget term=NONE' 'guifg=#cf3f48' 'ctermfg=167' s:bold_attr
+exe 'hi' 'EasyMotionShade term=NONE' 'guifg=#707d8b' 'ctermfg=244' 'guibg='.s:bg_gui 'ctermbg=231'
+hi! link GitGutterAdd SignifySignAdd
+hi! link GitGutterChange SignifySignChange
+hi! link GitGutterChangeDelete SignifySignChangeDelete
+hi! link GitGutterDelete SignifySignDelete
+hi HighlightedyankRegion term=NONE guibg=#ebe5d6 ctermbg=254
+if s:gui_running
+    exe 'hi' 'EasyMotionIncCursor term=NONE' 'guifg='.s:bg_gui 'ctermfg=231' 'guibg=#2a3644' 'ctermbg='.s:fg_cterm
+else
+    hi EasyMotionIncCursor term=NONE gui=reverse cterm=reverse
+endif
+hi plugDeleted term=NONE guifg=#707d8b ctermfg=244
+hi ConflictMarker term=NONE guibg=#e6a6a0 ctermbg=217
+exe 'hi' 'IndentGuidesOdd term=NONE' 'guibg='.s:bgweaker_gui 'ctermbg=255'
+hi IndentGuidesEven term=NONE guibg=#ebe5d6 ctermbg=254
+hi DiagnosticError term=NONE guifg=#cf3f48 ctermfg=167
+hi DiagnosticWarn term=NONE guifg=#d65f2e ctermfg=166
+hi DiagnosticInfo term=NONE guifg=#2d7db0 ctermfg=67
+hi DiagnosticHint term=NONE guifg=#707d8b ctermfg=244
+hi DiagnosticOk term=NONE guifg=#4c8a3a ctermfg=71
+hi! link DiagnosticVirtualTextError DiagnosticError
+hi! link DiagnosticVirtualTextWarn DiagnosticWarn
+hi! link DiagnosticVirtualTextInfo DiagnosticInfo
+hi! link DiagnosticVirtualTextHint DiagnosticHint
+hi! link DiagnosticVirtualTextOk DiagnosticOk
+hi DiagnosticUnderlineError term=NONE guibg=#e6a6a0 ctermbg=217
+exe 'hi' 'DiagnosticUnderlineWarn term=NONE' 'guibg='.s:darkgold_gui 'ctermbg=187'
+exe 'hi' 'DiagnosticUnderlineInfo term=NONE' 'guisp=#2d7db0' s:undercurl_attr
+exe 'hi' 'DiagnosticUnderlineHint term=NONE' 'guisp=#707d8b' s:underdashed_attr
+exe 'hi' 'DiagnosticUnderlineOk term=NONE' 'guisp=#4c8a3a' s:undercurl_attr
+exe 'hi' 'DiagnosticSignError term=NONE' 'guifg=#ebe5d6' 'ctermfg=254' 'guibg=#e6a6a0' 'ctermbg=217' s:bold_attr
+exe 'hi' 'DiagnosticSignWarn term=NONE' 'guifg=#bf6a3c' 'ctermfg=173' 'guibg=#ebe5d6' 'ctermbg=254' s:bold_attr
+hi DiagnosticSignInfo term=NONE guifg=#2d7db0 ctermfg=67 guibg=#ebe5d6 ctermbg=254
+hi DiagnosticSignHint term=NONE guifg=#707d8b ctermfg=244 guibg=#ebe5d6 ctermbg=254
+hi DiagnosticSignOk term=NONE guifg=#4c8a3a ctermfg=71 guibg=#ebe5d6 ctermbg=254
+exe 'hi' 'DiagnosticFloatingError term=NONE' 'guifg=#cf3f48' 'ctermfg=167' 'guibg='.s:bgweaker_gui 'ctermbg=255'
+exe 'hi' 'DiagnosticFloatingWarn term=NONE' 'guifg=#d65f2e' 'ctermfg=166' 'guibg='.s:bgweaker_gui 'ctermbg=255'
+exe 'hi' 'DiagnosticFloatingInfo term=NONE' 'guifg=#2d7db0' 'ctermfg=67' 'guibg='.s:bgweaker_gui 'ctermbg=255'
+exe 'hi' 'DiagnosticFloatingHint term=NONE' 'guifg=#707d8b' 'ctermfg=244' 'guibg='.s:bgweaker_gui 'ctermbg=255'
+exe 'hi' 'DiagnosticFloatingOk term=NONE' 'guifg=#4c8a3a' 'ctermfg=71' 'guibg='.s:bgweaker_gui 'ctermbg=255'
+hi DiagnosticUnnecessary term=NONE guifg=#707d8b ctermfg=244
+hi DiagnosticDeprecated term=NONE guifg=#707d8b ctermfg=244 gui=strikethrough cterm=strikethrough
+hi LspReferenceText term=NONE guibg=#ebe5d6 ctermbg=254
+hi! link LspReferenceRead LspReferenceText
+hi LspReferenceWrite term=NONE guibg=#d6cfbb ctermbg=187
+exe 'hi' 'LspInlayHint term=NONE' 'guifg=#939ba3' 'ctermfg=247' 'guibg='.s:bgweaker_gui 'ctermbg=255'
+hi LspCodeLens term=NONE guifg=#939ba3 ctermfg=247
+hi LspCodeLensSeparator term=NONE guifg=#c3ccd6 ctermfg=251
+exe 'hi' 'LspSignatureActiveParameter term=NONE' 'guifg=#ad7a00' 'ctermfg=136' s:bold_attr
+
+if g:spring_night_highlight_terminal
+    if has('nvim')
+        if s:gui_running || s:true_colors
+            let g:terminal_color_0 = '#fbf8ef'
+            let g:terminal_color_1 = '#c2242e'
+            let g:terminal_color_2 = '#4c8a3a'
+            let g:terminal_color_3 = '#ad7a00'
+            let g:terminal_color_4 = '#3c66c2'
+            let g:terminal_color_5 = '#7a4eaf'
+            let g:terminal_color_6 = '#2d7db0'
+            let g:terminal_color_7 = '#2a3644'
+            let g:terminal_color_8 = '#939ba3'
+            let g:terminal_color_9 = '#cf3f48'
+            let g:terminal_color_10 = '#5a9a1c'
+            let g:terminal_color_11 = '#877200'
+            let g:terminal_color_12 = '#5a80c8'
+            let g:terminal_color_13 = '#7a4eaf'
+            let g:terminal_color_14 = '#3891c0'
+            let g:terminal_color_15 = '#fdfdf8'
+        else
+            let g:terminal_color_0 = 231
+            let g:terminal_color_1 = 124
+            let g:terminal_color_2 = 71
+            let g:terminal_color_3 = 136
+            let g:terminal_color_4 = 25
+            let g:terminal_color_5 = 97
+            let g:terminal_color_6 = 67
+            let g:terminal_color_7 = 236
+            let g:terminal_color_8 = 247
+            let g:terminal_color_9 = 167
+            let g:terminal_color_10 = 64
+            let g:terminal_color_11 = 94
+            let g:terminal_color_12 = 68
+            let g:terminal_color_13 = 97
+            let g:terminal_color_14 = 31
+            let g:terminal_color_15 = 231
+        endif
+        let g:terminal_color_background = g:terminal_color_0
+        let g:terminal_color_foreground = g:terminal_color_7
+    elseif (s:gui_running || s:true_colors) && exists('*term_setansicolors')
+        let g:terminal_ansi_colors = ['#fbf8ef','#c2242e','#4c8a3a','#ad7a00','#3c66c2','#7a4eaf','#2d7db0','#2a3644','#939ba3','#cf3f48','#5a9a1c','#877200','#5a80c8','#7a4eaf','#3891c0','#fdfdf8']
+    endif
+endif
@@ -0,0 +1,430 @@
+-- spring-night: Calm-colored dark color scheme
+--
+-- Author: rhysd <lin90162@yahoo.co.jp>
+-- License: MIT
+--   Copyright (c) 2016 rhysd
+--
+-- PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+-- Generated by script vim-color-spring-night/gen/src/lua.rs
+
+if vim.o.background ~= 'dark' then
+    vim.o.background = 'dark'
+end
+
+if vim.g.colors_name then
+    vim.cmd('hi clear')
+end
+
+if vim.g.syntax_on then
+    vim.cmd('syntax reset')
+end
+
+vim.g.colors_name = 'spring-night'
+
+local function option(name, default)
+    local value = vim.g[name]
+    if value == nil then
+        value = default
+        vim.g[name] = value
+    end
+    return value ~= 0 and value ~= false
+end
+
+local italic_comments = option('spring_night_italic_comments', 0)
+local kill_italic = option('spring_night_kill_italic', 0)
+local kill_bold = option('spring_night_kill_bold', 0)
+local highlight_terminal = option('spring_night_highlight_terminal', 1)
+local cterm_italic = option('spring_night_cterm_italic', 0)
+
+local gui_running = vim.fn.has('gui_running') == 1
+local true_colors = vim.o.termguicolors
+local undercurl = gui_running
+local fancy_underline = gui_running
+local italic = not kill_italic
+local bold = not kill_bold
+cterm_italic = italic and cterm_italic
+
+if vim.g.spring_night_high_contrast == nil then
+    vim.g.spring_night_high_contrast = (not gui_running and true_colors) and 1 or 0
+end
+local high_contrast = option('spring_night_high_contrast', 0)
+
+local hl = vim.api.nvim_set_hl
+
+local bg_gui = high_contrast and '#132132' or '#334152'
+local bgweaker_gui = high_contrast and '#213243' or '#3a4b5c'
+local darkgold_gui = high_contrast and '#484000' or '#685800'
+local fg_cterm = high_contrast and 231 or 230
+
+hl(0, 'Boolean', { fg = '#fd8489', ctermfg = 210 })
+hl(0, 'Character', { fg = '#a9dd9d', ctermfg = 150 })
+hl(0, 'ColorColumn', { bg = '#536273', ctermbg = 238 })
+hl(0, 'Comment', { fg = '#8d9eb2', ctermfg = 103, italic = italic_comments and italic, cterm = { italic = italic_comments and cterm_italic } })
+hl(0, 'Conceal', { fg = '#fb8965', ctermfg = 209, bg = bg_gui, ctermbg = 233 })
+hl(0, 'Conditional', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'Constant', { fg = '#fd8489', ctermfg = 210 })
+hl(0, 'Cursor', { fg = bg_gui, ctermfg = 233, bg = '#fffeeb', ctermbg = fg_cterm })
+hl(0, 'lCursor', { link = 'Cursor' })
+hl(0, 'CursorColumn', { bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'CursorLine', { bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'CursorLineNr', { fg = '#e7d5ff', ctermfg = 189, bg = '#536273', ctermbg = 238 })
+hl(0, 'Define', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, 'Directory', { fg = '#a9dd9d', ctermfg = 150 })
+hl(0, 'EndOfBuffer', { fg = '#536273', ctermfg = 238 })
+hl(0, 'Error', { fg = '#fd8489', ctermfg = 210, bg = '#3a4b5c', ctermbg = 235, bold = bold, cterm = { bold = bold } })
+hl(0, 'ErrorMsg', { fg = '#fd8489', ctermfg = 210, bg = bg_gui, ctermbg = 233, bold = bold, cterm = { bold = bold } })
+hl(0, 'Float', { fg = '#fd8489', ctermfg = 210 })
+hl(0, 'NormalFloat', { fg = '#fffeeb', ctermfg = fg_cterm, bg = bgweaker_gui, ctermbg = 235 })
+hl(0, 'FloatBorder', { fg = '#8d9eb2', ctermfg = 103, bg = bgweaker_gui, ctermbg = 235 })
+hl(0, 'FoldColumn', { fg = '#e7d5ff', ctermfg = 189, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'Folded', { fg = '#e7d5ff', ctermfg = 189, bg = '#646f7c', ctermbg = 60 })
+hl(0, 'Function', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, 'Identifier', { fg = '#fedf81', ctermfg = 222, italic = italic, cterm = { italic = cterm_italic } })
+hl(0, 'IncSearch', { bg = '#a9667a', ctermbg = 132, underline = true, cterm = { underline = true } })
+hl(0, 'Keyword', { fg = '#f0eaaa', ctermfg = 229, bold = bold, cterm = { bold = bold } })
+hl(0, 'Label', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'LineNr', { fg = '#788898', ctermfg = 102, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'MatchParen', { fg = bg_gui, ctermfg = 233, bg = '#fedf81', ctermbg = 222, bold = bold, cterm = { bold = bold } })
+hl(0, 'ModeMsg', { fg = '#fedf81', ctermfg = 222 })
+hl(0, 'MoreMsg', { fg = '#a9dd9d', ctermfg = 150 })
+hl(0, 'NonText', { fg = '#646f7c', ctermfg = 60 })
+hl(0, 'Normal', { fg = '#fffeeb', ctermfg = fg_cterm, bg = bg_gui, ctermbg = 233 })
+hl(0, 'Number', { fg = '#fd8489', ctermfg = 210 })
+hl(0, 'Operator', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, 'Pmenu', { fg = '#e7d5ff', ctermfg = 189, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'PmenuSbar', { fg = '#fedf81', ctermfg = 222, bg = '#536273', ctermbg = 238 })
+hl(0, 'PmenuSel', { fg = '#fedf81', ctermfg = 222, bg = '#536273', ctermbg = 238 })
+hl(0, 'PmenuThumb', { fg = '#fedf81', ctermfg = 222, bg = '#8d9eb2', ctermbg = 103 })
+hl(0, 'PreProc', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, 'Question', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'Search', { bg = '#605779', ctermbg = 61, underline = true, cterm = { underline = true } })
+hl(0, 'SignColumn', { fg = '#fffeeb', ctermfg = fg_cterm, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'Special', { fg = '#f0eaaa', ctermfg = 229, bold = bold, cterm = { bold = bold } })
+hl(0, 'SpecialKey', { fg = '#607080', ctermfg = 60 })
+hl(0, 'SpecialComment', { fg = '#e7c6b7', ctermfg = 181 })
+if gui_running then
+    hl(0, 'SpellBad', { fg = '#fd8489', ctermfg = 210, sp = '#fd8489', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+else
+    hl(0, 'SpellBad', { fg = '#fd8489', ctermfg = 210, sp = '#fd8489', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+end
+if gui_running then
+    hl(0, 'SpellCap', { fg = '#e7d5ff', ctermfg = 189, sp = '#e7d5ff', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+else
+    hl(0, 'SpellCap', { fg = '#e7d5ff', ctermfg = 189, sp = '#e7d5ff', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+end
+if gui_running then
+    hl(0, 'SpellLocal', { fg = '#fd8489', ctermfg = 210, sp = '#fd8489', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+else
+    hl(0, 'SpellLocal', { fg = '#fd8489', ctermfg = 210, sp = '#fd8489', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+end
+if gui_running then
+    hl(0, 'SpellRare', { fg = '#f0eaaa', ctermfg = 229, sp = '#f0eaaa', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+else
+    hl(0, 'SpellRare', { fg = '#f0eaaa', ctermfg = 229, sp = '#f0eaaa', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+end
+hl(0, 'Statement', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'StatusLine', { fg = '#fffeeb', ctermfg = fg_cterm, bg = '#536273', ctermbg = 238, bold = bold, cterm = { bold = bold } })
+hl(0, 'StatusLineNC', { fg = '#8d9eb2', ctermfg = 103, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'StatusLineTerm', { link = 'StatusLine' })
+hl(0, 'StatusLineTermNC', { link = 'StatusLineNC' })
+hl(0, 'StorageClass', { fg = '#fedf81', ctermfg = 222, italic = italic, cterm = { italic = cterm_italic } })
+hl(0, 'String', { fg = '#a9dd9d', ctermfg = 150 })
+hl(0, 'TabLine', { fg = '#8d9eb2', ctermfg = 103, bg = '#536273', ctermbg = 238 })
+hl(0, 'TabLineFill', { fg = '#3a4b5c', ctermfg = 235 })
+hl(0, 'TabLineSel', { fg = '#fedf81', ctermfg = 222, bg = bg_gui, ctermbg = 233, bold = bold, cterm = { bold = bold } })
+hl(0, 'Tag', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, 'Title', { fg = '#fedf81', ctermfg = 222, bold = bold, cterm = { bold = bold } })
+hl(0, 'Todo', { fg = bg_gui, ctermfg = 233, bg = '#fd8489', ctermbg = 210, bold = bold, cterm = { bold = bold } })
+hl(0, 'ToolbarButton', { fg = '#fedf81', ctermfg = 222, bg = bg_gui, ctermbg = 233, bold = bold, cterm = { bold = bold } })
+hl(0, 'ToolbarLine', { fg = '#8d9eb2', ctermfg = 103, bg = '#536273', ctermbg = 238 })
+hl(0, 'Type', { fg = '#fedf81', ctermfg = 222 })
+hl(0, 'Underlined', { fg = '#a8d2eb', ctermfg = 153, underline = true, cterm = { underline = true } })
+hl(0, 'VertSplit', { fg = '#3a4b5c', ctermfg = 235, bg = bg_gui, ctermbg = 233 })
+hl(0, 'Visual', { bg = '#70495d', ctermbg = 95 })
+hl(0, 'WarningMsg', { fg = '#fb8965', ctermfg = 209, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'WildMenu', { fg = bg_gui, ctermfg = 233, bg = '#fedf81', ctermbg = 222 })
+hl(0, 'cmakeArguments', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'cmakeOperators', { fg = '#fd8489', ctermfg = 210 })
+hl(0, 'cStorageClass', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'cTypedef', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'DiffAdd', { bg = '#5f8770', ctermbg = 65, bold = bold, cterm = { bold = bold } })
+hl(0, 'DiffChange', { bg = darkgold_gui, ctermbg = 58, bold = bold, cterm = { bold = bold } })
+hl(0, 'DiffDelete', { fg = '#fffeeb', ctermfg = fg_cterm, bg = '#ab6560', ctermbg = 167, bold = bold, cterm = { bold = bold } })
+hl(0, 'DiffText', { bg = bg_gui, ctermbg = 233 })
+hl(0, 'diffAdded', { fg = '#a9dd9d', ctermfg = 150 })
+hl(0, 'diffFile', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'diffIndexLine', { fg = '#fedf81', ctermfg = 222 })
+hl(0, 'diffNewFile', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'diffRemoved', { fg = '#fd8489', ctermfg = 210 })
+hl(0, 'gitcommitOverflow', { bg = '#ab6560', ctermbg = 167 })
+hl(0, 'gitcommitSummary', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'gitcommitSelectedFile', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'gitconfigSection', { fg = '#a8d2eb', ctermfg = 153, bold = bold, cterm = { bold = bold } })
+hl(0, 'glslQualifier', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'goBuiltins', { fg = '#fd8489', ctermfg = 210 })
+hl(0, 'helpExample', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'helpCommand', { fg = '#e7d5ff', ctermfg = 189 })
+hl(0, 'htmlBold', { bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'htmlLinkText', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'htmlTagName', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, 'javaScriptBraces', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, 'makeCommands', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'manFooter', { fg = '#a9dd9d', ctermfg = 150 })
+hl(0, 'manHeader', { fg = '#a9dd9d', ctermfg = 150 })
+hl(0, 'manLongOptionDesc', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'manOptionDesc', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'manReference', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'manSectionHeading', { fg = '#fedf81', ctermfg = 222 })
+hl(0, 'manSubHeading', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, 'markdownCode', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'markdownUrl', { fg = '#8d9eb2', ctermfg = 103 })
+hl(0, 'ocamlConstructor', { fg = '#fedf81', ctermfg = 222 })
+hl(0, 'ocamlKeyChar', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'ocamlKeyword', { fg = '#fedf81', ctermfg = 222 })
+hl(0, 'ocamlFunDef', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'plantumlColonLine', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'pythonBuiltin', { fg = '#fd8489', ctermfg = 210 })
+hl(0, 'qfFileName', { fg = '#fedf81', ctermfg = 222 })
+hl(0, 'qfLineNr', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'rstEmphasis', { bg = '#3a4b5c', ctermbg = 235, italic = italic, cterm = { italic = cterm_italic } })
+hl(0, 'rstStrongEmphasis', { bg = '#536273', ctermbg = 238, bold = bold, cterm = { bold = bold } })
+hl(0, 'rubyFunction', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'rubyIdentifier', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'rustEnumVariant', { fg = '#fedf81', ctermfg = 222 })
+hl(0, 'rustFuncCall', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, 'rustCommentLineDoc', { fg = '#e7c6b7', ctermfg = 181 })
+hl(0, 'scalaInstanceDeclaration', { fg = '#fedf81', ctermfg = 222 })
+hl(0, 'tomlTable', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'tomlTableArray', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'tomlKey', { fg = '#fedf81', ctermfg = 222 })
+hl(0, 'tmuxCommands', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'tmuxFlags', { fg = '#fedf81', ctermfg = 222 })
+hl(0, 'tmuxFormatString', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'typescriptBraces', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, 'typescriptAsyncFuncKeyword', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'typescriptKeywordOp', { fg = '#f0eaaa', ctermfg = 229, bold = bold, cterm = { bold = bold } })
+hl(0, 'vimfilerColumn__SizeLine', { fg = '#8d9eb2', ctermfg = 103 })
+hl(0, 'vimfilerClosedFile', { fg = '#a9dd9d', ctermfg = 150 })
+hl(0, 'vimCommand', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'watListDelimiter', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, 'watInstGeneral', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'watInstGetSet', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'watInstWithType', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'watUnnamedVar', { fg = '#e7d5ff', ctermfg = 189 })
+hl(0, 'zshDelimiter', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'zshPrecommand', { fg = '#fd8489', ctermfg = 210 })
+hl(0, 'debugPC', { fg = bg_gui, ctermfg = 233, bg = '#a8d2eb', ctermbg = 153 })
+hl(0, 'debugBreakpoint', { fg = bg_gui, ctermfg = 233, bg = '#fedf81', ctermbg = 222 })
+hl(0, 'zigMultilineStringDelimiter', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, 'ALEWarningSign', { link = 'DiagnosticSignWarn' })
+hl(0, 'ALEErrorSign', { link = 'DiagnosticSignError' })
+hl(0, 'ALEInfoSign', { bg = '#646f7c', ctermbg = 60 })
+hl(0, 'ALEError', { link = 'DiagnosticUnderlineError' })
+hl(0, 'ALEWarning', { link = 'DiagnosticUnderlineWarn' })
+hl(0, 'Flake8_Error', { fg = '#fd8489', ctermfg = 210, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'Flake8_Warning', { fg = '#f0eaaa', ctermfg = 229, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'Flake8_PyFlake', { fg = '#a8d2eb', ctermfg = 153, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'Flake8_Complexity', { fg = '#a8d2eb', ctermfg = 153, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'Flake8_Naming', { fg = '#a8d2eb', ctermfg = 153, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'SignifySignAdd', { fg = '#a9dd9d', ctermfg = 150, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'SignifySignChange', { fg = '#f0eaaa', ctermfg = 229, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'SignifySignChangeDelete', { fg = '#fedf81', ctermfg = 222, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'SignifySignDelete', { fg = '#fd8489', ctermfg = 210, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'CleverFChar', { fg = bg_gui, ctermfg = 233, bg = '#fd8489', ctermbg = 210 })
+hl(0, 'CleverFDirect', { fg = bg_gui, ctermfg = 233, bg = '#fd8489', ctermbg = 210 })
+hl(0, 'DirvishArg', { fg = '#f0eaaa', ctermfg = 229, bold = bold, cterm = { bold = bold } })
+hl(0, 'EasyMotionTarget', { fg = '#fd8489', ctermfg = 210, bold = bold, cterm = { bold = bold } })
+hl(0, 'EasyMotionShade', { fg = '#8d9eb2', ctermfg = 103, bg = bg_gui, ctermbg = 233 })
+hl(0, 'GitGutterAdd', { link = 'SignifySignAdd' })
+hl(0, 'GitGutterChange', { link = 'SignifySignChange' })
+hl(0, 'GitGutterChangeDelete', { link = 'SignifySignChangeDelete' })
+hl(0, 'GitGutterDelete', { link = 'SignifySignDelete' })
+hl(0, 'HighlightedyankRegion', { bg = '#3a4b5c', ctermbg = 235 })
+if gui_running then
+    hl(0, 'EasyMotionIncCursor', { fg = bg_gui, ctermfg = 233, bg = '#fffeeb', ctermbg = fg_cterm })
+else
+    hl(0, 'EasyMotionIncCursor', { reverse = true, cterm = { reverse = true } })
+end
+hl(0, 'plugDeleted', { fg = '#8d9eb2', ctermfg = 103 })
+hl(0, 'ConflictMarker', { bg = '#ab6560', ctermbg = 167 })
+hl(0, 'IndentGuidesOdd', { bg = bgweaker_gui, ctermbg = 235 })
+hl(0, 'IndentGuidesEven', { bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'DiagnosticError', { fg = '#fd8489', ctermfg = 210 })
+hl(0, 'DiagnosticWarn', { fg = '#fb8965', ctermfg = 209 })
+hl(0, 'DiagnosticInfo', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, 'DiagnosticHint', { fg = '#8d9eb2', ctermfg = 103 })
+hl(0, 'DiagnosticOk', { fg = '#a9dd9d', ctermfg = 150 })
+hl(0, 'DiagnosticVirtualTextError', { link = 'DiagnosticError' })
+hl(0, 'DiagnosticVirtualTextWarn', { link = 'DiagnosticWarn' })
+hl(0, 'DiagnosticVirtualTextInfo', { link = 'DiagnosticInfo' })
+hl(0, 'DiagnosticVirtualTextHint', { link = 'DiagnosticHint' })
+hl(0, 'DiagnosticVirtualTextOk', { link = 'DiagnosticOk' })
+hl(0, 'DiagnosticUnderlineError', { bg = '#ab6560', ctermbg = 167 })
+hl(0, 'DiagnosticUnderlineWarn', { bg = darkgold_gui, ctermbg = 58 })
+hl(0, 'DiagnosticUnderlineInfo', { sp = '#a8d2eb', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+hl(0, 'DiagnosticUnderlineHint', { sp = '#8d9eb2', underdashed = fancy_underline, underline = not fancy_underline, cterm = { underdashed = fancy_underline, underline = not fancy_underline } })
+hl(0, 'DiagnosticUnderlineOk', { sp = '#a9dd9d', undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })
+hl(0, 'DiagnosticSignError', { fg = '#3a4b5c', ctermfg = 235, bg = '#ab6560', ctermbg = 167, bold = bold, cterm = { bold = bold } })
+hl(0, 'DiagnosticSignWarn', { fg = '#f0aa8a', ctermfg = 216, bg = '#3a4b5c', ctermbg = 235, bold = bold, cterm = { bold = bold } })
+hl(0, 'DiagnosticSignInfo', { fg = '#a8d2eb', ctermfg = 153, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'DiagnosticSignHint', { fg = '#8d9eb2', ctermfg = 103, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'DiagnosticSignOk', { fg = '#a9dd9d', ctermfg = 150, bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'DiagnosticFloatingError', { fg = '#fd8489', ctermfg = 210, bg = bgweaker_gui, ctermbg = 235 })
+hl(0, 'DiagnosticFloatingWarn', { fg = '#fb8965', ctermfg = 209, bg = bgweaker_gui, ctermbg = 235 })
+hl(0, 'DiagnosticFloatingInfo', { fg = '#a8d2eb', ctermfg = 153, bg = bgweaker_gui, ctermbg = 235 })
+hl(0, 'DiagnosticFloatingHint', { fg = '#8d9eb2', ctermfg = 103, bg = bgweaker_gui, ctermbg = 235 })
+hl(0, 'DiagnosticFloatingOk', { fg = '#a9dd9d', ctermfg = 150, bg = bgweaker_gui, ctermbg = 235 })
+hl(0, 'DiagnosticUnnecessary', { fg = '#8d9eb2', ctermfg = 103 })
+hl(0, 'DiagnosticDeprecated', { fg = '#8d9eb2', ctermfg = 103, strikethrough = true, cterm = { strikethrough = true } })
+hl(0, 'LspReferenceText', { bg = '#3a4b5c', ctermbg = 235 })
+hl(0, 'LspReferenceRead', { link = 'LspReferenceText' })
+hl(0, 'LspReferenceWrite', { bg = '#536273', ctermbg = 238 })
+hl(0, 'LspInlayHint', { fg = '#788898', ctermfg = 102, bg = bgweaker_gui, ctermbg = 235 })
+hl(0, 'LspCodeLens', { fg = '#788898', ctermfg = 102 })
+hl(0, 'LspCodeLensSeparator', { fg = '#607080', ctermfg = 60 })
+hl(0, 'LspSignatureActiveParameter', { fg = '#fedf81', ctermfg = 222, bold = bold, cterm = { bold = bold } })
+hl(0, '@lsp.type.class', { link = '@type' })
+hl(0, '@lsp.type.comment', { link = '@comment' })
+hl(0, '@lsp.type.decorator', { link = '@attribute' })
+hl(0, '@lsp.type.enum', { link = '@type' })
+hl(0, '@lsp.type.enumMember', { link = '@constructor' })
+hl(0, '@lsp.type.function', { link = '@function' })
+hl(0, '@lsp.type.interface', { link = '@type' })
+hl(0, '@lsp.type.keyword', { link = '@keyword' })
+hl(0, '@lsp.type.macro', { link = '@function.macro' })
+hl(0, '@lsp.type.method', { link = '@function.method' })
+hl(0, '@lsp.type.namespace', { link = '@module' })
+hl(0, '@lsp.type.number', { link = '@number' })
+hl(0, '@lsp.type.operator', { link = '@operator' })
+hl(0, '@lsp.type.parameter', { link = '@variable.parameter' })
+hl(0, '@lsp.type.property', { link = '@property' })
+hl(0, '@lsp.type.string', { link = '@string' })
+hl(0, '@lsp.type.struct', { link = '@type' })
+hl(0, '@lsp.type.type', { link = '@type' })
+hl(0, '@lsp.type.typeParameter', { link = '@type.definition' })
+hl(0, '@lsp.type.variable', { link = '@variable' })
+hl(0, '@lsp.mod.deprecated', { fg = '#8d9eb2', ctermfg = 103, strikethrough = true, cterm = { strikethrough = true } })
+hl(0, '@lsp.typemod.function.defaultLibrary', { link = '@function.builtin' })
+hl(0, '@lsp.typemod.variable.defaultLibrary', { link = '@variable.builtin' })
+hl(0, '@variable', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, '@variable.builtin', { fg = '#fd8489', ctermfg = 210 })
+hl(0, '@variable.parameter', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, '@variable.member', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, '@constant', { link = 'Constant' })
+hl(0, '@constant.builtin', { fg = '#fd8489', ctermfg = 210 })
+hl(0, '@constant.macro', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, '@module', { fg = '#fedf81', ctermfg = 222 })
+hl(0, '@module.builtin', { fg = '#fd8489', ctermfg = 210 })
+hl(0, '@label', { link = 'Label' })
+hl(0, '@string', { link = 'String' })
+hl(0, '@string.documentation', { fg = '#e7c6b7', ctermfg = 181 })
+hl(0, '@string.regexp', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, '@string.escape', { fg = '#f0eaaa', ctermfg = 229, bold = bold, cterm = { bold = bold } })
+hl(0, '@string.special', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, '@string.special.symbol', { fg = '#fd8489', ctermfg = 210 })
+hl(0, '@string.special.url', { fg = '#a8d2eb', ctermfg = 153, underline = true, cterm = { underline = true } })
+hl(0, '@character', { link = 'Character' })
+hl(0, '@character.special', { fg = '#f0eaaa', ctermfg = 229, bold = bold, cterm = { bold = bold } })
+hl(0, '@boolean', { link = 'Boolean' })
+hl(0, '@number', { link = 'Number' })
+hl(0, '@number.float', { link = 'Float' })
+hl(0, '@type', { link = 'Type' })
+hl(0, '@type.builtin', { fg = '#fedf81', ctermfg = 222 })
+hl(0, '@type.definition', { fg = '#fedf81', ctermfg = 222 })
+hl(0, '@attribute', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, '@attribute.builtin', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, '@property', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, '@function', { link = 'Function' })
+hl(0, '@function.builtin', { fg = '#fd8489', ctermfg = 210 })
+hl(0, '@function.call', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, '@function.macro', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, '@function.method', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, '@function.method.call', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, '@constructor', { fg = '#fedf81', ctermfg = 222 })
+hl(0, '@operator', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, '@keyword', { link = 'Keyword' })
+hl(0, '@keyword.coroutine', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, '@keyword.function', { fg = '#f0eaaa', ctermfg = 229, bold = bold, cterm = { bold = bold } })
+hl(0, '@keyword.operator', { fg = '#f0eaaa', ctermfg = 229, bold = bold, cterm = { bold = bold } })
+hl(0, '@keyword.import', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, '@keyword.type', { fg = '#fedf81', ctermfg = 222, italic = italic, cterm = { italic = cterm_italic } })
+hl(0, '@keyword.modifier', { fg = '#fedf81', ctermfg = 222, italic = italic, cterm = { italic = cterm_italic } })
+hl(0, '@keyword.repeat', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, '@keyword.return', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, '@keyword.debug', { fg = '#fd8489', ctermfg = 210 })
+hl(0, '@keyword.exception', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, '@keyword.conditional', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, '@keyword.conditional.ternary', { link = '@operator' })
+hl(0, '@keyword.directive', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, '@keyword.directive.define', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, '@punctuation.delimiter', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, '@punctuation.bracket', { fg = '#fffeeb', ctermfg = fg_cterm })
+hl(0, '@punctuation.special', { fg = '#f0eaaa', ctermfg = 229, bold = bold, cterm = { bold = bold } })
+hl(0, '@comment', { link = 'Comment' })
+hl(0, '@comment.documentation', { fg = '#e7c6b7', ctermfg = 181 })
+hl(0, '@comment.error', { fg = bg_gui, ctermfg = 233, bg = '#fd8489', ctermbg = 210, bold = bold, cterm = { bold = bold } })
+hl(0, '@comment.warning', { fg = bg_gui, ctermfg = 233, bg = '#fb8965', ctermbg = 209, bold = bold, cterm = { bold = bold } })
+hl(0, '@comment.todo', { fg = bg_gui, ctermfg = 233, bg = '#fd8489', ctermbg = 210, bold = bold, cterm = { bold = bold } })
+hl(0, '@comment.note', { fg = bg_gui, ctermfg = 233, bg = '#a8d2eb', ctermbg = 153, bold = bold, cterm = { bold = bold } })
+hl(0, '@markup.strong', { bold = bold, cterm = { bold = bold } })
+hl(0, '@markup.italic', { italic = italic, cterm = { italic = cterm_italic } })
+hl(0, '@markup.underline', { underline = true, cterm = { underline = true } })
+hl(0, '@markup.strikethrough', { strikethrough = true, cterm = { strikethrough = true } })
+hl(0, '@markup.heading', { fg = '#fedf81', ctermfg = 222, bold = bold, cterm = { bold = bold } })
+hl(0, '@markup.quote', { fg = '#8d9eb2', ctermfg = 103 })
+hl(0, '@markup.math', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, '@markup.link', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, '@markup.link.label', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, '@markup.link.url', { fg = '#8d9eb2', ctermfg = 103, underline = true, cterm = { underline = true } })
+hl(0, '@markup.raw', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, '@markup.raw.block', { fg = '#f0eaaa', ctermfg = 229 })
+hl(0, '@markup.list', { fg = '#a8d2eb', ctermfg = 153 })
+hl(0, '@markup.list.checked', { fg = '#a9dd9d', ctermfg = 150 })
+hl(0, '@markup.list.unchecked', { fg = '#8d9eb2', ctermfg = 103 })
+hl(0, '@diff.plus', { fg = '#a9dd9d', ctermfg = 150 })
+hl(0, '@diff.minus', { fg = '#fd8489', ctermfg = 210 })
+hl(0, '@diff.delta', { fg = '#fedf81', ctermfg = 222 })
+hl(0, '@tag', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, '@tag.builtin', { fg = '#f0aa8a', ctermfg = 216 })
+hl(0, '@tag.attribute', { fg = '#fedf81', ctermfg = 222 })
+hl(0, '@tag.delimiter', { fg = '#8d9eb2', ctermfg = 103 })
+
+if highlight_terminal then
+    if gui_running or true_colors then
+        vim.g.terminal_color_0 = '#132132'
+        vim.g.terminal_color_1 = '#ff6a6f'
+        vim.g.terminal_color_2 = '#a9dd9d'
+        vim.g.terminal_color_3 = '#fedf81'
+        vim.g.terminal_color_4 = '#7098e6'
+        vim.g.terminal_color_5 = '#e7d5ff'
+        vim.g.terminal_color_6 = '#a8d2eb'
+        vim.g.terminal_color_7 = '#fffeeb'
+        vim.g.terminal_color_8 = '#788898'
+        vim.g.terminal_color_9 = '#fd8489'
+        vim.g.terminal_color_10 = '#c9fd88'
+        vim.g.terminal_color_11 = '#f0eaaa'
+        vim.g.terminal_color_12 = '#98b8e6'
+        vim.g.terminal_color_13 = '#e7d5ff'
+        vim.g.terminal_color_14 = '#b8e2fb'
+        vim.g.terminal_color_15 = '#ffffff'
+    else
+        vim.g.terminal_color_0 = 233
+        vim.g.terminal_color_1 = 203
+        vim.g.terminal_color_2 = 150
+        vim.g.terminal_color_3 = 222
+        vim.g.terminal_color_4 = 69
+        vim.g.terminal_color_5 = 189
+        vim.g.terminal_color_6 = 153
+        vim.g.terminal_color_7 = 231
+        vim.g.terminal_color_8 = 102
+        vim.g.terminal_color_9 = 210
+        vim.g.terminal_color_10 = 149
+        vim.g.terminal_color_11 = 229
+        vim.g.terminal_color_12 = 111
+        vim.g.terminal_color_13 = 189
+        vim.g.terminal_color_14 = 195
+        vim.g.terminal_color_15 = 231
+    end
+    vim.g.terminal_color_background = vim.g.terminal_color_0
+    vim.g.terminal_color_foreground = vim.g.terminal_color_7
+end
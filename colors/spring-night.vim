@@ -7,6 +7,13 @@
 " PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
 " Generated by script vim-color-spring-night/gen/src/colorscheme.rs
 
+" Neovim loads the Lua version of this colorscheme which sets highlights with
+" nvim_set_hl() instead of building `:hi` commands with `:execute`.
+if has('nvim-0.8')
+    execute 'luafile' fnameescape(expand('<sfile>:p:r') . '.lua')
+    finish
+endif
+
 " Optimization:
 " `:set background=dark` has some side effects which takes a time.
 " Avoid the side effects when the value is already 'dark'.
@@ -41,6 +48,19 @@ let s:true_colors = has('termguicolors') && &termguicolors
 let s:undercurl_attr = s:gui_running ? 'gui=undercurl cterm=undercurl' : 'gui=underline cterm=underline'
 let s:italic_attr = g:spring_night_kill_italic ? '' : g:spring_night_cterm_italic ? 'gui=italic cterm=italic' : 'gui=italic'
 let s:bold_attr = g:spring_night_kill_bold ? '' : 'gui=bold cterm=bold'
+" underdouble, underdotted and underdashed are available since Vim 9.0
+let s:fancy_underline = s:gui_running && v:version >= 900
+let s:underdouble_attr = s:fancy_underline ? 'gui=underdouble cterm=underdouble' : 'gui=underline cterm=underline'
+let s:underdotted_attr = s:fancy_underline ? 'gui=underdotted cterm=underdotted' : 'gui=underline cterm=underline'
+let s:underdashed_attr = s:fancy_underline ? 'gui=underdashed cterm=underdashed' : 'gui=underline cterm=underline'
+" Attributes for combining them in one `gui=` or `cterm=` argument
+let s:bold = g:spring_night_kill_bold ? '' : 'bold,'
+let s:italic = g:spring_night_kill_italic ? '' : 'italic,'
+let s:cterm_italic = g:spring_night_cterm_italic ? s:italic : ''
+let s:undercurl = s:gui_running ? 'undercurl,' : 'underline,'
+let s:underdouble = s:fancy_underline ? 'underdouble,' : 'underline,'
+let s:underdotted = s:fancy_underline ? 'underdotted,' : 'underline,'
+let s:underdashed = s:fancy_underline ? 'underdashed,' : 'underline,'
 
 if exists('g:spring_night_high_contrast')
     if type(g:spring_night_high_contrast) != type(0)
@@ -64,7 +84,7 @@ exe 'hi' 'Conceal term=NONE' 'guifg=#fb8965' 'ctermfg=209' 'guibg='.s:bg_gui 'ct
 hi Conditional term=NONE guifg=#a8d2eb ctermfg=153
 hi Constant term=NONE guifg=#fd8489 ctermfg=210
 exe 'hi' 'Cursor term=NONE' 'guifg='.s:bg_gui 'ctermfg=233' 'guibg=#fffeeb' 'ctermbg='.s:fg_cterm
-exe 'hi' 'lCursor term=NONE' 'guifg='.s:bg_gui 'ctermfg=233' 'guibg=#fffeeb' 'ctermbg='.s:fg_cterm
+hi! link lCursor Cursor
 hi CursorColumn term=NONE guibg=#3a4b5c ctermbg=235
 hi CursorLine term=NONE guibg=#3a4b5c ctermbg=235 gui=NONE cterm=NONE
 hi CursorLineNr term=NONE guifg=#e7d5ff ctermfg=189 guibg=#536273 ctermbg=238
@@ -90,7 +110,7 @@ hi MoreMsg term=NONE guifg=#a9dd9d ctermfg=150
 hi NonText term=NONE guifg=#646f7c ctermfg=60
 exe 'hi' 'Normal term=NONE' 'guifg=#fffeeb' 'ctermfg='.s:fg_cterm 'guibg='.s:bg_gui 'ctermbg=233'
 hi Number term=NONE guifg=#fd8489 ctermfg=210
-hi Operater term=NONE guifg=#f0aa8a ctermfg=216
+hi Operator term=NONE guifg=#f0aa8a ctermfg=216
 hi Pmenu term=NONE guifg=#e7d5ff ctermfg=189 guibg=#3a4b5c ctermbg=235
 hi PmenuSbar term=NONE guifg=#fedf81 ctermfg=222 guibg=#536273 ctermbg=238
 hi PmenuSel term=NONE guifg=#fedf81 ctermfg=222 guibg=#536273 ctermbg=238
@@ -125,8 +145,8 @@ endif
 hi Statement term=NONE guifg=#a8d2eb ctermfg=153
 exe 'hi' 'StatusLine term=NONE' 'guifg=#fffeeb' 'ctermfg='.s:fg_cterm 'guibg=#536273' 'ctermbg=238' s:bold_attr
 hi StatusLineNC term=NONE guifg=#8d9eb2 ctermfg=103 guibg=#3a4b5c ctermbg=235 gui=NONE cterm=NONE
-exe 'hi' 'StatusLineTerm term=NONE' 'guifg=#fffeeb' 'ctermfg='.s:fg_cterm 'guibg=#536273' 'ctermbg=238' s:bold_attr
-hi StatusLineTermNC term=NONE guifg=#8d9eb2 ctermfg=103 guibg=#3a4b5c ctermbg=235 gui=NONE cterm=NONE
+hi! link StatusLineTerm StatusLine
+hi! link StatusLineTermNC StatusLineNC
 exe 'hi' 'StorageClass term=NONE' 'guifg=#fedf81' 'ctermfg=222' s:italic_attr
 hi String term=NONE guifg=#a9dd9d ctermfg=150
 hi TabLine term=NONE guifg=#8d9eb2 ctermfg=103 guibg=#536273 ctermbg=238
@@ -156,9 +176,9 @@ hi diffFile term=NONE guifg=#f0eaaa ctermfg=229
 hi diffIndexLine term=NONE guifg=#fedf81 ctermfg=222
 hi diffNewFile term=NONE guifg=#f0eaaa ctermfg=229
 hi diffRemoved term=NONE guifg=#fd8489 ctermfg=210
-hi gitCommitOverflow term=NONE guibg=#ab6560 ctermbg=167
-hi gitCommitSummary term=NONE guifg=#f0eaaa ctermfg=229
-hi gitCommitSelectedFile term=NONE guifg=#a8d2eb ctermfg=153
+hi gitcommitOverflow term=NONE guibg=#ab6560 ctermbg=167
+hi gitcommitSummary term=NONE guifg=#f0eaaa ctermfg=229
+hi gitcommitSelectedFile term=NONE guifg=#a8d2eb ctermfg=153
 exe 'hi' 'gitconfigSection term=NONE' 'guifg=#a8d2eb' 'ctermfg=153' s:bold_attr
 hi glslQualifier term=NONE guifg=#f0eaaa ctermfg=229
 hi goBuiltins term=NONE guifg=#fd8489 ctermfg=210
@@ -214,13 +234,13 @@ hi watUnnamedVar term=NONE guifg=#e7d5ff ctermfg=189
 hi zshDelimiter term=NONE guifg=#a8d2eb ctermfg=153
 hi zshPrecommand term=NONE guifg=#fd8489 ctermfg=210
 exe 'hi' 'debugPC term=NONE' 'guifg='.s:bg_gui 'ctermfg=233' 'guibg=#a8d2eb' 'ctermbg=153'
-exe 'hi' 'debugBreakPoint term=NONE' 'guifg='.s:bg_gui 'ctermfg=233' 'guibg=#fedf81' 'ctermbg=222'
+exe 'hi' 'debugBreakpoint term=NONE' 'guifg='.s:bg_gui 'ctermfg=233' 'guibg=#fedf81' 'ctermbg=222'
 hi zigMultilineStringDelimiter term=NONE guifg=#f0eaaa ctermfg=229
-exe 'hi' 'ALEWarningSign term=NONE' 'guifg=#f0aa8a' 'ctermfg=216' 'guibg=#3a4b5c' 'ctermbg=235' s:bold_attr
-exe 'hi' 'ALEErrorSign term=NONE' 'guifg=#3a4b5c' 'ctermfg=235' 'guibg=#ab6560' 'ctermbg=167' s:bold_attr
+hi! link ALEWarningSign DiagnosticSignWarn
+hi! link ALEErrorSign DiagnosticSignError
 hi ALEInfoSign term=NONE guibg=#646f7c ctermbg=60
-hi ALEError term=NONE guibg=#ab6560 ctermbg=167
-exe 'hi' 'ALEWarning term=NONE' 'guibg='.s:darkgold_gui 'ctermbg=58'
+hi! link ALEError DiagnosticUnderlineError
+hi! link ALEWarning DiagnosticUnderlineWarn
 hi Flake8_Error term=NONE guifg=#fd8489 ctermfg=210 guibg=#3a4b5c ctermbg=235
 hi Flake8_Warning term=NONE guifg=#f0eaaa ctermfg=229 guibg=#3a4b5c ctermbg=235
 hi Flake8_PyFlake term=NONE guifg=#a8d2eb ctermfg=153 guibg=#3a4b5c ctermbg=235
@@ -235,10 +255,10 @@ exe 'hi' 'CleverFDirect term=NONE' 'guifg='.s:bg_gui 'ctermfg=233' 'guibg=#fd848
 exe 'hi' 'DirvishArg term=NONE' 'guifg=#f0eaaa' 'ctermfg=229' s:bold_attr
 exe 'hi' 'EasyMotionTarget term=NONE' 'guifg=#fd8489' 'ctermfg=210' s:bold_attr
 exe 'hi' 'EasyMotionShade term=NONE' 'guifg=#8d9eb2' 'ctermfg=103' 'guibg='.s:bg_gui 'ctermbg=233'
-hi GitGutterAdd term=NONE guifg=#a9dd9d ctermfg=150 guibg=#3a4b5c ctermbg=235
-hi GitGutterChange term=NONE guifg=#f0eaaa ctermfg=229 guibg=#3a4b5c ctermbg=235
-hi GitGutterChangeDelete term=NONE guifg=#fedf81 ctermfg=222 guibg=#3a4b5c ctermbg=235
-hi GitGutterDelete term=NONE guifg=#fd8489 ctermfg=210 guibg=#3a4b5c ctermbg=235
+hi! link GitGutterAdd SignifySignAdd
+hi! link GitGutterChange SignifySignChange
+hi! link GitGutterChangeDelete SignifySignChangeDelete
+hi! link GitGutterDelete SignifySignDelete
 hi HighlightedyankRegion term=NONE guibg=#3a4b5c ctermbg=235
 if s:gui_running
     exe 'hi' 'EasyMotionIncCursor term=NONE' 'guifg='.s:bg_gui 'ctermfg=233' 'guibg=#fffeeb' 'ctermbg='.s:fg_cterm
@@ -249,6 +269,40 @@ hi plugDeleted term=NONE guifg=#8d9eb2 ctermfg=103
 hi ConflictMarker term=NONE guibg=#ab6560 ctermbg=167
 exe 'hi' 'IndentGuidesOdd term=NONE' 'guibg='.s:bgweaker_gui 'ctermbg=235'
 hi IndentGuidesEven term=NONE guibg=#3a4b5c ctermbg=235
+hi DiagnosticError term=NONE guifg=#fd8489 ctermfg=210
+hi DiagnosticWarn term=NONE guifg=#fb8965 ctermfg=209
+hi DiagnosticInfo term=NONE guifg=#a8d2eb ctermfg=153
+hi DiagnosticHint term=NONE guifg=#8d9eb2 ctermfg=103
+hi DiagnosticOk term=NONE guifg=#a9dd9d ctermfg=150
+hi! link DiagnosticVirtualTextError DiagnosticError
+hi! link DiagnosticVirtualTextWarn DiagnosticWarn
+hi! link DiagnosticVirtualTextInfo DiagnosticInfo
+hi! link DiagnosticVirtualTextHint DiagnosticHint
+hi! link DiagnosticVirtualTextOk DiagnosticOk
+hi DiagnosticUnderlineError term=NONE guibg=#ab6560 ctermbg=167
+exe 'hi' 'DiagnosticUnderlineWarn term=NONE' 'guibg='.s:darkgold_gui 'ctermbg=58'
+exe 'hi' 'DiagnosticUnderlineInfo term=NONE' 'guisp=#a8d2eb' s:undercurl_attr
+exe 'hi' 'DiagnosticUnderlineHint term=NONE' 'guisp=#8d9eb2' s:underdashed_attr
+exe 'hi' 'DiagnosticUnderlineOk term=NONE' 'guisp=#a9dd9d' s:undercurl_attr
+exe 'hi' 'DiagnosticSignError term=NONE' 'guifg=#3a4b5c' 'ctermfg=235' 'guibg=#ab6560' 'ctermbg=167' s:bold_attr
+exe 'hi' 'DiagnosticSignWarn term=NONE' 'guifg=#f0aa8a' 'ctermfg=216' 'guibg=#3a4b5c' 'ctermbg=235' s:bold_attr
+hi DiagnosticSignInfo term=NONE guifg=#a8d2eb ctermfg=153 guibg=#3a4b5c ctermbg=235
+hi DiagnosticSignHint term=NONE guifg=#8d9eb2 ctermfg=103 guibg=#3a4b5c ctermbg=235
+hi DiagnosticSignOk term=NONE guifg=#a9dd9d ctermfg=150 guibg=#3a4b5c ctermbg=235
+exe 'hi' 'DiagnosticFloatingError term=NONE' 'guifg=#fd8489' 'ctermfg=210' 'guibg='.s:bgweaker_gui 'ctermbg=235'
+exe 'hi' 'DiagnosticFloatingWarn term=NONE' 'guifg=#fb8965' 'ctermfg=209' 'guibg='.s:bgweaker_gui 'ctermbg=235'
+exe 'hi' 'DiagnosticFloatingInfo term=NONE' 'guifg=#a8d2eb' 'ctermfg=153' 'guibg='.s:bgweaker_gui 'ctermbg=235'
+exe 'hi' 'DiagnosticFloatingHint term=NONE' 'guifg=#8d9eb2' 'ctermfg=103' 'guibg='.s:bgweaker_gui 'ctermbg=235'
+exe 'hi' 'DiagnosticFloatingOk term=NONE' 'guifg=#a9dd9d' 'ctermfg=150' 'guibg='.s:bgweaker_gui 'ctermbg=235'
+hi DiagnosticUnnecessary term=NONE guifg=#8d9eb2 ctermfg=103
+hi DiagnosticDeprecated term=NONE guifg=#8d9eb2 ctermfg=103 gui=strikethrough cterm=strikethrough
+hi LspReferenceText term=NONE guibg=#3a4b5c ctermbg=235
+hi! link LspReferenceRead LspReferenceText
+hi LspReferenceWrite term=NONE guibg=#536273 ctermbg=238
+exe 'hi' 'LspInlayHint term=NONE' 'guifg=#788898' 'ctermfg=102' 'guibg='.s:bgweaker_gui 'ctermbg=235'
+hi LspCodeLens term=NONE guifg=#788898 ctermfg=102
+hi LspCodeLensSeparator term=NONE guifg=#607080 ctermfg=60
+exe 'hi' 'LspSignatureActiveParameter term=NONE' 'guifg=#fedf81' 'ctermfg=222' s:bold_attr
 
 if g:spring_night_highlight_terminal
     if has('nvim')
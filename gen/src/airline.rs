@@ -1,6 +1,13 @@
+use crate::contrast::ColorPair;
 use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
 use std::collections::HashMap;
 use std::io::{Result, Write};
+use std::path::PathBuf;
+
+const MODES: [&str; 5] = ["normal", "insert", "visual", "replace", "inactive"];
+
+type Section<'a> = (&'static str, (&'a str, &'a str)); // Pair of section name and (fg, bg) colors
 
 #[derive(Debug, PartialEq, Default)]
 struct ModeColors<'a> {
@@ -88,12 +95,24 @@ impl<'a> AirlineTheme<'a> {
         }
     }
 
-    fn write_header(&self, w: &mut impl Write) -> Result<()> {
+    /// Color names of (fg, bg) for label, info and main sections of the mode
+    pub fn sections(&self, mode: &str) -> [(&'a str, &'a str); 3] {
+        let colors = &self.modes[mode];
+        [colors.label, colors.info, colors.main]
+    }
+
+    /// Color names of (fg, bg) for error and warning sections
+    pub fn error_warning(&self) -> [(&'a str, &'a str); 2] {
+        [self.error, self.warning]
+    }
+
+    fn write_header(&self, w: &mut dyn Write) -> Result<()> {
         let red = &self.palette["red"];
+        let variant = self.palette.variant();
         // Header
         write!(
             w,
-            r#"" vim-airline theme for spring-night colorscheme
+            r#"" vim-airline theme for {name} colorscheme
 "
 " Author: rhysd <lin90162@yahoo.co.jp>
 " License: MIT
@@ -102,14 +121,16 @@ impl<'a> AirlineTheme<'a> {
 " PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
 " Generated by script vim-color-spring-night/gen/{source}
 
-let g:airline#themes#spring_night#palette = {{}}
+let g:airline#themes#{ident}#palette = {{}}
 
-let g:airline#themes#spring_night#palette.accents = {{
+let g:airline#themes#{ident}#palette.accents = {{
 \   'red': ['{guifg}', '', {ctermfg}, '', ''],
 \ }}
 
 "#,
             source = file!(),
+            name = variant.name(),
+            ident = variant.ident(),
             guifg = red.gui.normal(),
             ctermfg = red.cterm.normal(),
         )
@@ -117,7 +138,7 @@ let g:airline#themes#spring_night#palette.accents = {{
 
     fn write_section_color(
         &self,
-        w: &mut impl Write,
+        w: &mut dyn Write,
         name: &str,
         (fg, bg): (&'a str, &'a str),
     ) -> Result<()> {
@@ -133,65 +154,109 @@ let g:airline#themes#spring_night#palette.accents = {{
         )
     }
 
-    fn write_error_warning(&self, w: &mut impl Write) -> Result<()> {
+    fn write_error_warning(&self, w: &mut dyn Write) -> Result<()> {
         self.write_section_color(w, "error", self.error)?;
         self.write_section_color(w, "warning", self.warning)
     }
 
-    fn write_mode_colors(&self, w: &mut impl Write, name: &str) -> Result<()> {
-        let mode = &self.modes[name];
+    /// Palettes of sections written for each mode. Each group consists of the palette of the mode
+    /// and its variants like `normal_modified`. The last group is for `insert_paste`.
+    fn mode_palettes(&self) -> Vec<Vec<(String, Vec<Section<'a>>)>> {
+        let mut groups = vec![];
+        for name in MODES {
+            let mode = &self.modes[name];
+            let mut group = vec![(
+                name.to_string(),
+                vec![
+                    ("a", mode.label),
+                    ("b", mode.info),
+                    ("c", mode.main),
+                    ("x", mode.main),
+                    ("y", mode.info),
+                    ("z", mode.label),
+                ],
+            )];
+            if let Some(modified) = mode.modified {
+                let main_fg = mode.modified_main.unwrap_or(modified);
+                group.push((
+                    format!("{name}_modified"),
+                    vec![
+                        ("a", (mode.label.0, modified)),
+                        ("b", (modified, self.info_mod)),
+                        ("c", (main_fg, mode.main.1)),
+                    ],
+                ));
+            }
+            groups.push(group);
+        }
 
-        writeln!(w, "let g:airline#themes#spring_night#palette.{name} = {{")?;
-        self.write_section_color(w, "a", mode.label)?;
-        self.write_section_color(w, "b", mode.info)?;
-        self.write_section_color(w, "c", mode.main)?;
-        self.write_section_color(w, "x", mode.main)?;
-        self.write_section_color(w, "y", mode.info)?;
-        self.write_section_color(w, "z", mode.label)?;
-        self.write_error_warning(w)?;
-        writeln!(w, "\\ }}")?;
+        let normal_map = &self.modes["normal"];
+        let insert_map = &self.modes["insert"];
+        groups.push(vec![(
+            "insert_paste".to_string(),
+            vec![
+                ("a", (insert_map.label.0, self.paste)),
+                ("b", (self.paste, self.info_mod)),
+                ("c", (self.paste, normal_map.main.1)),
+            ],
+        )]);
+        groups
+    }
 
-        if let Some(modified) = mode.modified {
-            let main_fg = mode.modified_main.unwrap_or(modified);
-            writeln!(
-                w,
-                "let g:airline#themes#spring_night#palette.{name}_modified = {{",
-            )?;
-            self.write_section_color(w, "a", (mode.label.0, modified))?;
-            self.write_section_color(w, "b", (modified, self.info_mod))?;
-            self.write_section_color(w, "c", (main_fg, mode.main.1))?;
-            self.write_error_warning(w)?;
-            writeln!(w, "\\ }}")?;
+    /// Pairs of foreground and background colors of all sections in all modes
+    pub fn color_pairs(&self) -> Vec<ColorPair<'a>> {
+        let mut pairs = vec![];
+        for (name, sections) in self.mode_palettes().into_iter().flatten() {
+            for (i, &(section, (fg, bg))) in sections.iter().enumerate() {
+                // Sections x, y and z repeat the colors of sections a, b and c
+                if sections[..i].iter().any(|(_, c)| *c == (fg, bg)) {
+                    continue;
+                }
+                pairs.push(ColorPair::new(format!("{name} {section}"), fg, bg));
+            }
         }
+        pairs.push(ColorPair::new("error", self.error.0, self.error.1));
+        pairs.push(ColorPair::new("warning", self.warning.0, self.warning.1));
+        pairs
+    }
+}
+
+impl ThemeWriter for AirlineTheme<'_> {
+    fn target(&self) -> &'static str {
+        "airline"
+    }
 
-        writeln!(w)
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&[
+            "autoload",
+            "airline",
+            "themes",
+            &format!("{}.vim", variant.ident()),
+        ])
     }
 
-    pub fn write_to(&self, w: &mut impl Write) -> Result<()> {
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
         self.write_header(w)?;
 
-        for mode in &["normal", "insert", "visual", "replace", "inactive"] {
-            self.write_mode_colors(w, mode)?;
+        let ident = self.palette.variant().ident();
+        for group in self.mode_palettes() {
+            for (name, sections) in group {
+                writeln!(w, "let g:airline#themes#{ident}#palette.{name} = {{")?;
+                for (section, colors) in sections {
+                    self.write_section_color(w, section, colors)?;
+                }
+                self.write_error_warning(w)?;
+                writeln!(w, "\\ }}")?;
+            }
+            writeln!(w)?;
         }
 
-        let normal_map = &self.modes["normal"];
-        let insert_map = &self.modes["insert"];
-
-        // Insert Paste
-        writeln!(
-            w,
-            "let g:airline#themes#spring_night#palette.insert_paste = {{",
-        )?;
-        self.write_section_color(w, "a", (insert_map.label.0, self.paste))?;
-        self.write_section_color(w, "b", (self.paste, self.info_mod))?;
-        self.write_section_color(w, "c", (self.paste, normal_map.main.1))?;
-        self.write_error_warning(w)?;
-        writeln!(w, "\\ }}\n")?;
-
         // Inactive Modified is a special case
+        let normal_map = &self.modes["normal"];
         writeln!(
             w,
-            "let g:airline#themes#spring_night#palette.inactive_modified = {{",
+            "let g:airline#themes#{ident}#palette.inactive_modified = {{"
         )?;
         let modified = &self.palette[normal_map.modified.unwrap()];
         let guifg = modified.gui.normal();
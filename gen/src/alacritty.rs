@@ -1,6 +1,9 @@
+use crate::contrast::ColorPair;
 use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
 use std::fmt;
 use std::io::{Result, Write};
+use std::path::PathBuf;
 
 #[derive(Clone, Copy, Debug)]
 enum Brightness {
@@ -20,7 +23,7 @@ impl fmt::Display for Brightness {
 }
 
 #[derive(Debug)]
-struct AnsiColors<'a> {
+pub struct AnsiColors<'a> {
     brightness: Brightness,
     foreground: &'a str,
     black: &'a str,
@@ -33,6 +36,22 @@ struct AnsiColors<'a> {
     white: &'a str,
 }
 
+impl<'a> AnsiColors<'a> {
+    /// Color names of black, red, green, yellow, blue, magenta, cyan and white in this order
+    pub fn colors(&self) -> [&'a str; 8] {
+        [
+            self.black,
+            self.red,
+            self.green,
+            self.yellow,
+            self.blue,
+            self.magenta,
+            self.cyan,
+            self.white,
+        ]
+    }
+}
+
 type Color<'a> = (&'a str, &'a str); // Pair of foreground/background colors
 
 #[derive(Debug)]
@@ -102,11 +121,58 @@ impl<'a> AlacrittyTheme<'a> {
         }
     }
 
+    pub fn background(&self) -> &'a str {
+        self.background
+    }
+
+    pub fn foreground(&self) -> &'a str {
+        self.normal.foreground
+    }
+
+    pub fn bright_foreground(&self) -> &'a str {
+        self.bright.foreground
+    }
+
+    /// Color names of (fg, bg) for selected text
+    pub fn selection(&self) -> (&'a str, &'a str) {
+        self.selection
+    }
+
+    /// Color names of (fg, bg) for search matches
+    pub fn search(&self) -> (&'a str, &'a str) {
+        self.search
+    }
+
+    /// Color names of (fg, bg) for the first character of hint labels
+    pub fn hint(&self) -> (&'a str, &'a str) {
+        self.hint_head
+    }
+
+    pub fn dim(&self) -> &AnsiColors<'a> {
+        &self.dim
+    }
+
+    pub fn normal(&self) -> &AnsiColors<'a> {
+        &self.normal
+    }
+
+    pub fn bright(&self) -> &AnsiColors<'a> {
+        &self.bright
+    }
+
+    /// Color names of ANSI 16 colors. 0-7 are normal colors and 8-15 are bright colors
+    pub fn ansi_colors(&self) -> [&'a str; 16] {
+        let mut colors = [""; 16];
+        colors[..8].copy_from_slice(&self.normal.colors());
+        colors[8..].copy_from_slice(&self.bright.colors());
+        colors
+    }
+
     fn color(&self, name: &str) -> &'_ str {
         self.palette[name].gui.normal()
     }
 
-    fn write_color(&self, w: &mut impl Write, name: &str, (fg, bg): Color<'_>) -> Result<()> {
+    fn write_color(&self, w: &mut dyn Write, name: &str, (fg, bg): Color<'_>) -> Result<()> {
         let (fg, bg) = (self.color(fg), self.color(bg));
         writeln!(
             w,
@@ -114,10 +180,10 @@ impl<'a> AlacrittyTheme<'a> {
         )
     }
 
-    fn write_header_comment(&self, w: &mut impl Write) -> Result<()> {
+    fn write_header_comment(&self, w: &mut dyn Write) -> Result<()> {
         writeln!(
             w,
-            r#"# Alacritty theme for spring-night colorscheme
+            r#"# Alacritty theme for {name} colorscheme
 #
 # Author: rhysd <lin90162@yahoo.co.jp>
 # License: MIT
@@ -125,11 +191,12 @@ impl<'a> AlacrittyTheme<'a> {
 #
 # PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
 # Generated by script vim-color-spring-night/gen/{file}"#,
+            name = self.palette.variant().name(),
             file = file!(),
         )
     }
 
-    fn write_root_section(&self, w: &mut impl Write) -> Result<()> {
+    fn write_root_section(&self, w: &mut dyn Write) -> Result<()> {
         writeln!(w)?;
         writeln!(w, "[colors]")?;
         self.write_color(w, "footer_bar", self.footer_bar)?;
@@ -137,7 +204,7 @@ impl<'a> AlacrittyTheme<'a> {
     }
 
     #[rustfmt::skip]
-    fn write_primary_section(&self, w: &mut impl Write) -> Result<()> {
+    fn write_primary_section(&self, w: &mut dyn Write) -> Result<()> {
         writeln!(w)?;
         writeln!(w, "[colors.primary]")?;
         writeln!(w, "background = \"{}\"",        self.color(self.background))?;
@@ -147,7 +214,7 @@ impl<'a> AlacrittyTheme<'a> {
     }
 
     #[rustfmt::skip]
-    fn write_ansi_colors_section(&self, w: &mut impl Write, colors: &AnsiColors<'a>) -> Result<()> {
+    fn write_ansi_colors_section(&self, w: &mut dyn Write, colors: &AnsiColors<'a>) -> Result<()> {
         writeln!(w)?;
         writeln!(w, "[colors.{}]",      colors.brightness)?;
         writeln!(w, "black = \"{}\"",   self.color(colors.black))?;
@@ -160,14 +227,14 @@ impl<'a> AlacrittyTheme<'a> {
         writeln!(w, "white = \"{}\"",   self.color(colors.white))
     }
 
-    fn write_search_section(&self, w: &mut impl Write) -> Result<()> {
+    fn write_search_section(&self, w: &mut dyn Write) -> Result<()> {
         writeln!(w)?;
         writeln!(w, "[colors.search]")?;
         self.write_color(w, "matches", self.search)?;
         self.write_color(w, "focused_match", self.search_focus)
     }
 
-    fn write_selection_section(&self, w: &mut impl Write) -> Result<()> {
+    fn write_selection_section(&self, w: &mut dyn Write) -> Result<()> {
         let (fg, bg) = self.selection;
         writeln!(w)?;
         writeln!(w, "[colors.selection]")?;
@@ -175,14 +242,46 @@ impl<'a> AlacrittyTheme<'a> {
         writeln!(w, "background = \"{}\"", self.color(bg))
     }
 
-    fn write_hints_section(&self, w: &mut impl Write) -> Result<()> {
+    fn write_hints_section(&self, w: &mut dyn Write) -> Result<()> {
         writeln!(w)?;
         writeln!(w, "[colors.hints]")?;
         self.write_color(w, "start", self.hint_head)?;
         self.write_color(w, "end", self.hint_tail)
     }
 
-    pub fn write_to(&self, w: &mut impl Write) -> Result<()> {
+    /// Pairs of foreground and background colors rendered by Alacritty
+    pub fn color_pairs(&self) -> Vec<ColorPair<'a>> {
+        let mut pairs = vec![ColorPair::new(
+            "primary",
+            self.normal.foreground,
+            self.background,
+        )];
+        for (label, (fg, bg)) in [
+            ("selection", self.selection),
+            ("search matches", self.search),
+            ("search focused_match", self.search_focus),
+            ("footer_bar", self.footer_bar),
+            ("line_indicator", self.line_indicator),
+            ("hints start", self.hint_head),
+            ("hints end", self.hint_tail),
+        ] {
+            pairs.push(ColorPair::new(label, fg, bg));
+        }
+        pairs
+    }
+}
+
+impl ThemeWriter for AlacrittyTheme<'_> {
+    fn target(&self) -> &'static str {
+        "alacritty"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&["alacritty", &format!("{}.toml", variant.ident())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
         self.write_header_comment(w)?;
         self.write_root_section(w)?;
         self.write_primary_section(w)?;
@@ -198,33 +297,26 @@ impl<'a> AlacrittyTheme<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use regex::Regex;
+    use crate::theme::tests::assert_palette_colors;
     use std::str;
     use toml_edit::{DocumentMut, Item as TomlItem, Value as TomlValue};
 
-    #[test]
-    fn test_default_alacritty_theme() {
-        let p = Palette::default();
-        let w = AlacrittyTheme::new(&p);
+    fn assert_alacritty_theme(p: &Palette) {
+        let w = AlacrittyTheme::new(p);
         let mut out = vec![];
         w.write_to(&mut out).unwrap();
         let src = str::from_utf8(&out).unwrap();
         let doc: DocumentMut = src.parse().expect(src);
+        assert_palette_colors(p, src);
 
-        let hex_color = Regex::new(r"^#[[:xdigit:]]{6}$").unwrap();
-        let assert_color = move |path: &str| {
+        let assert_color = |path: &str| {
             let mut item = doc.as_item();
             for idx in path.split('.') {
                 item = item.get(idx).expect(path);
             }
-            let TomlItem::Value(v) = item else {
-                panic!("{path} is not a primitive: {item:?}");
-            };
-            let TomlValue::String(s) = v else {
-                panic!("{path} is not a string: {v:?}");
+            let TomlItem::Value(TomlValue::String(_)) = item else {
+                panic!("{path} is not a string: {item:?}");
             };
-            let s = s.value().as_str();
-            assert!(hex_color.is_match(s), "{path} is not a hex color: {s:?}");
         };
 
         for path in [
@@ -255,4 +347,14 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_default_alacritty_theme() {
+        assert_alacritty_theme(&Palette::default());
+    }
+
+    #[test]
+    fn test_morning_alacritty_theme() {
+        assert_alacritty_theme(&Palette::morning());
+    }
 }
@@ -0,0 +1,354 @@
+// Color math used for deriving and checking colors in palettes.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    // Parse "#rrggbb" color code
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#')?;
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some(Self::new(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    // Color of the xterm-256 color index. 0~15 are the default colors of xterm though actual colors
+    // of them depend on terminals.
+    pub fn from_xterm(index: u8) -> Self {
+        const SYSTEM: [Rgb; 16] = [
+            Rgb::new(0x00, 0x00, 0x00),
+            Rgb::new(0x80, 0x00, 0x00),
+            Rgb::new(0x00, 0x80, 0x00),
+            Rgb::new(0x80, 0x80, 0x00),
+            Rgb::new(0x00, 0x00, 0x80),
+            Rgb::new(0x80, 0x00, 0x80),
+            Rgb::new(0x00, 0x80, 0x80),
+            Rgb::new(0xc0, 0xc0, 0xc0),
+            Rgb::new(0x80, 0x80, 0x80),
+            Rgb::new(0xff, 0x00, 0x00),
+            Rgb::new(0x00, 0xff, 0x00),
+            Rgb::new(0xff, 0xff, 0x00),
+            Rgb::new(0x00, 0x00, 0xff),
+            Rgb::new(0xff, 0x00, 0xff),
+            Rgb::new(0x00, 0xff, 0xff),
+            Rgb::new(0xff, 0xff, 0xff),
+        ];
+        const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+        match index {
+            0..=15 => SYSTEM[index as usize],
+            16..=231 => {
+                let i = (index - 16) as usize;
+                Self::new(CUBE[i / 36], CUBE[i / 6 % 6], CUBE[i % 6])
+            }
+            232..=255 => {
+                let v = 8 + (index - 232) * 10;
+                Self::new(v, v, v)
+            }
+        }
+    }
+
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    // Linear RGB values in 0.0~1.0
+    pub fn linear(self) -> [f64; 3] {
+        fn linearize(c: u8) -> f64 {
+            let c = c as f64 / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        [linearize(self.r), linearize(self.g), linearize(self.b)]
+    }
+
+    // sRGB components in 0.0~1.0
+    pub fn srgb(self) -> [f64; 3] {
+        [self.r, self.g, self.b].map(|c| c as f64 / 255.0)
+    }
+
+    // Display P3 components in 0.0~1.0 of the same color. Display P3 shares the D65 white point and
+    // the transfer function with sRGB, so only primaries are converted in linear space.
+    pub fn display_p3(self) -> [f64; 3] {
+        #[rustfmt::skip]
+        const SRGB_TO_P3: [[f64; 3]; 3] = [
+            [0.822_461_968_9, 0.177_538_031_1, 0.000_000_000_0],
+            [0.033_194_198_9, 0.966_805_801_1, 0.000_000_000_0],
+            [0.017_082_630_7, 0.072_397_440_7, 0.910_519_928_6],
+        ];
+        fn gamma(c: f64) -> f64 {
+            if c <= 0.003_130_8 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        }
+        let linear = self.linear();
+        SRGB_TO_P3.map(|row| {
+            let c: f64 = row.iter().zip(linear).map(|(m, c)| m * c).sum();
+            gamma(c).clamp(0.0, 1.0)
+        })
+    }
+
+    // Relative luminance defined in WCAG 2
+    pub fn luminance(self) -> f64 {
+        let [r, g, b] = self.linear();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    // Contrast ratio defined in WCAG 2. The result is in 1.0~21.0
+    pub fn contrast_ratio(self, other: Self) -> f64 {
+        let (l1, l2) = (self.luminance(), other.luminance());
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
+    // Lightness contrast (Lc) of text color on background color defined in APCA 0.0.98G-4g. The
+    // result is in about -108.0~106.0. Negative value means light text on dark background.
+    pub fn apca_contrast(self, bg: Self) -> f64 {
+        fn screen_luminance(c: Rgb) -> f64 {
+            let y = [(c.r, 0.2126729), (c.g, 0.7151522), (c.b, 0.0721750)]
+                .iter()
+                .map(|&(c, k)| (c as f64 / 255.0).powf(2.4) * k)
+                .sum::<f64>();
+            if y < 0.022 {
+                y + (0.022 - y).powf(1.414)
+            } else {
+                y
+            }
+        }
+
+        let (text, bg) = (screen_luminance(self), screen_luminance(bg));
+        if (bg - text).abs() < 0.0005 {
+            return 0.0;
+        }
+        let lc = if bg > text {
+            let sapc = (bg.powf(0.56) - text.powf(0.57)) * 1.14;
+            if sapc < 0.1 {
+                0.0
+            } else {
+                sapc - 0.027
+            }
+        } else {
+            let sapc = (bg.powf(0.65) - text.powf(0.62)) * 1.14;
+            if sapc > -0.1 {
+                0.0
+            } else {
+                sapc + 0.027
+            }
+        };
+        lc * 100.0
+    }
+
+    // CIE L*a*b* with D65 white point
+    fn lab(self) -> [f64; 3] {
+        let [r, g, b] = self.linear();
+        let x = (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047;
+        let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+        let z = (0.0193339 * r + 0.1191920 * g + 0.9503041 * b) / 1.08883;
+        let f = |t: f64| {
+            if t > 216.0 / 24389.0 {
+                t.cbrt()
+            } else {
+                (24389.0 / 27.0 * t + 16.0) / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+        [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+    }
+
+    // Perceptual distance between two colors (CIEDE2000)
+    pub fn distance(self, other: Self) -> f64 {
+        ciede2000(self.lab(), other.lab())
+    }
+
+    // Nearest xterm-256 color index. System colors (0~15) are not candidates since they are
+    // usually customized by terminals.
+    pub fn nearest_xterm(self) -> u8 {
+        (16..=255)
+            .min_by(|&i, &j| {
+                let di = self.distance(Self::from_xterm(i));
+                let dj = self.distance(Self::from_xterm(j));
+                di.total_cmp(&dj)
+            })
+            .unwrap()
+    }
+}
+
+fn ciede2000([l1, a1, b1]: [f64; 3], [l2, a2, b2]: [f64; 3]) -> f64 {
+    let pow7 = |x: f64| x.powi(7);
+    let hue = |b: f64, a: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+
+    let c_bar = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - (pow7(c_bar) / (pow7(c_bar) + pow7(25.0))).sqrt());
+    let (a1, a2) = ((1.0 + g) * a1, (1.0 + g) * a2);
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+    let (h1, h2) = (hue(b1, a1), hue(b2, a2));
+
+    let dl = l2 - l1;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else if h2 - h1 < -180.0 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1
+    };
+    let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let cos = |deg: f64| deg.to_radians().cos();
+    let t =
+        1.0 - 0.17 * cos(h_bar - 30.0) + 0.24 * cos(2.0 * h_bar) + 0.32 * cos(3.0 * h_bar + 6.0)
+            - 0.20 * cos(4.0 * h_bar - 63.0);
+    let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (pow7(c_bar) / (pow7(c_bar) + pow7(25.0))).sqrt();
+    let s_l = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar;
+    let s_h = 1.0 + 0.015 * c_bar * t;
+    let r_t = -(2.0 * d_theta).to_radians().sin() * r_c;
+
+    let (dl, dc, dh) = (dl / s_l, dc / s_c, dh / s_h);
+    (dl * dl + dc * dc + dh * dh + r_t * dc * dh).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(Rgb::from_hex("#12abEF"), Some(Rgb::new(0x12, 0xab, 0xef)));
+        assert_eq!(Rgb::from_hex("#12abEF").unwrap().hex(), "#12abef");
+        for invalid in ["", "#", "12abef", "#12abe", "#12abefa", "#12abeg"] {
+            assert_eq!(Rgb::from_hex(invalid), None, "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn test_xterm_colors() {
+        assert_eq!(Rgb::from_xterm(1), Rgb::new(0x80, 0, 0));
+        assert_eq!(Rgb::from_xterm(16), Rgb::new(0, 0, 0));
+        assert_eq!(Rgb::from_xterm(137), Rgb::new(0xaf, 0x87, 0x5f));
+        assert_eq!(Rgb::from_xterm(221), Rgb::new(0xff, 0xd7, 0x5f));
+        assert_eq!(Rgb::from_xterm(231), Rgb::new(0xff, 0xff, 0xff));
+        assert_eq!(Rgb::from_xterm(232), Rgb::new(8, 8, 8));
+        assert_eq!(Rgb::from_xterm(255), Rgb::new(0xee, 0xee, 0xee));
+    }
+
+    #[test]
+    fn test_ciede2000() {
+        // Test data from "The CIEDE2000 Color-Difference Formula: Implementation Notes,
+        // Supplementary Test Data, and Mathematical Observations" by G. Sharma et al.
+        #[rustfmt::skip]
+        let testcases = [
+            ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485],  2.0425),
+            ([50.0, -1.0, 2.0],        [50.0, 0.0, 0.0],       2.3669),
+            ([50.0, 2.49, -0.001],     [50.0, -2.49, 0.0009],  7.1792),
+            ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+            ([2.0776, 0.0795, -1.135], [0.9033, -0.0636, -0.5514], 0.9082),
+        ];
+        for (lab1, lab2, expected) in testcases {
+            let actual = ciede2000(lab1, lab2);
+            assert!(
+                (actual - expected).abs() < 1e-4,
+                "{lab1:?} {lab2:?}: {actual}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_contrast() {
+        let black = Rgb::new(0, 0, 0);
+        let white = Rgb::new(255, 255, 255);
+        assert!((black.contrast_ratio(white) - 21.0).abs() < 1e-9);
+        assert!((white.contrast_ratio(black) - 21.0).abs() < 1e-9);
+        assert!((white.contrast_ratio(white) - 1.0).abs() < 1e-9);
+        let gray = Rgb::from_hex("#767676").unwrap();
+        assert!((gray.contrast_ratio(white) - 4.54).abs() < 0.01);
+
+        // Values from APCA reference implementation
+        #[rustfmt::skip]
+        let testcases = [
+            ("#888888", "#ffffff",  63.056),
+            ("#ffffff", "#888888", -68.541),
+            ("#000000", "#aaaaaa",  58.146),
+            ("#aaaaaa", "#000000", -56.24),
+            ("#112233", "#ddeeff",  91.66),
+            ("#ddeeff", "#112233", -93.066),
+            ("#ffffff", "#ffffff",   0.0),
+        ];
+        for (text, bg, expected) in testcases {
+            let text = Rgb::from_hex(text).unwrap();
+            let bg = Rgb::from_hex(bg).unwrap();
+            let actual = text.apca_contrast(bg);
+            assert!(
+                (actual - expected).abs() < 0.01,
+                "{text:?} on {bg:?}: {actual}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_p3() {
+        #[rustfmt::skip]
+        let testcases = [
+            ("#ffffff", [1.0, 1.0, 1.0]),
+            ("#000000", [0.0, 0.0, 0.0]),
+            ("#ff0000", [0.917_488, 0.200_287, 0.138_561]),
+            ("#00ff00", [0.458_402, 0.985_265, 0.298_295]),
+            ("#0000ff", [0.0, 0.0, 0.959_588]),
+            ("#808080", [0.501_961, 0.501_961, 0.501_961]),
+        ];
+        for (hex, expected) in testcases {
+            let actual = Rgb::from_hex(hex).unwrap().display_p3();
+            for (a, e) in actual.iter().zip(expected) {
+                assert!((a - e).abs() < 0.001, "{hex}: {actual:?} vs {expected:?}");
+            }
+        }
+        assert_eq!(Rgb::new(255, 0, 51).srgb(), [1.0, 0.0, 0.2]);
+    }
+
+    #[test]
+    fn test_nearest_xterm() {
+        assert_eq!(Rgb::from_hex("#000000").unwrap().nearest_xterm(), 16);
+        assert_eq!(Rgb::from_hex("#ffffff").unwrap().nearest_xterm(), 231);
+        assert_eq!(Rgb::from_hex("#af875f").unwrap().nearest_xterm(), 137);
+        assert_eq!(Rgb::from_hex("#b6955b").unwrap().nearest_xterm(), 137);
+        for i in 16..=255 {
+            let c = Rgb::from_xterm(i);
+            assert_eq!(Rgb::from_xterm(c.nearest_xterm()), c, "index {i}");
+        }
+    }
+}
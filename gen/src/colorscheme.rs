@@ -1,42 +1,218 @@
+use crate::contrast::{ColorMode, ColorPair};
 use crate::palette::{ColorCode, Palette};
+use crate::theme::{self, ThemeWriter};
 use std::fmt::Display;
 use std::io::{Result, Write};
+use std::ops::BitOr;
+use std::path::PathBuf;
 
 const NONE_COLOR: ColorCode<&'static str> = ColorCode::Normal("NONE");
 
-type ColorName = Option<&'static str>;
-
-#[derive(Debug, PartialEq)]
-enum HiAttr {
-    Nothing,
-    Bold,
-    Italic,
-    Underline,
-    Reverse,
-    None,
-    CommentItalic,
-    Undercurl,
+pub type ColorName = Option<&'static str>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum HiAttr {
+    Nothing = 0,
+    Bold = 1 << 0,
+    Italic = 1 << 1,
+    Underline = 1 << 2,
+    Reverse = 1 << 3,
+    None = 1 << 4,
+    CommentItalic = 1 << 5,
+    Undercurl = 1 << 6,
+    Strikethrough = 1 << 7,
+    Underdouble = 1 << 8,
+    Underdotted = 1 << 9,
+    Underdashed = 1 << 10,
+    Nocombine = 1 << 11,
+    Standout = 1 << 12,
+}
+
+impl HiAttr {
+    const ALL: [HiAttr; 13] = [
+        Self::Bold,
+        Self::Italic,
+        Self::Underline,
+        Self::Reverse,
+        Self::None,
+        Self::CommentItalic,
+        Self::Undercurl,
+        Self::Strikethrough,
+        Self::Underdouble,
+        Self::Underdotted,
+        Self::Underdashed,
+        Self::Nocombine,
+        Self::Standout,
+    ];
+
+    // Underline styles which fall back to plain underline when Vim is not running on GUI
+    pub fn is_fancy_underline(self) -> bool {
+        matches!(
+            self,
+            Self::Undercurl | Self::Underdouble | Self::Underdotted | Self::Underdashed
+        )
+    }
+}
+
+// Set of attributes combined with `|` like `Bold | Italic`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HiAttrs(u16);
+
+impl HiAttrs {
+    pub fn contains(self, attr: HiAttr) -> bool {
+        self.0 & attr as u16 != 0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = HiAttr> {
+        HiAttr::ALL.into_iter().filter(move |&a| self.contains(a))
+    }
+
+    /// Font styles for editors other than Vim with the default options. Fancy underlines fall back
+    /// to "underline" and `CommentItalic` is ignored since italic comments are disabled by default.
+    pub fn font_styles(self) -> Vec<&'static str> {
+        let mut styles = vec![];
+        if self.contains(HiAttr::Bold) {
+            styles.push("bold");
+        }
+        if self.contains(HiAttr::Italic) {
+            styles.push("italic");
+        }
+        if self
+            .iter()
+            .any(|a| a == HiAttr::Underline || a.is_fancy_underline())
+        {
+            styles.push("underline");
+        }
+        if self.contains(HiAttr::Strikethrough) {
+            styles.push("strikethrough");
+        }
+        styles
+    }
+}
+
+impl From<HiAttr> for HiAttrs {
+    fn from(attr: HiAttr) -> Self {
+        Self(attr as u16)
+    }
+}
+
+impl BitOr for HiAttr {
+    type Output = HiAttrs;
+    fn bitor(self, rhs: Self) -> HiAttrs {
+        HiAttrs(self as u16 | rhs as u16)
+    }
+}
+
+impl BitOr<HiAttr> for HiAttrs {
+    type Output = HiAttrs;
+    fn bitor(self, rhs: HiAttr) -> HiAttrs {
+        HiAttrs(self.0 | rhs as u16)
+    }
 }
 
 #[derive(Debug)]
-struct HiCommand {
-    name: &'static str,
-    fg: ColorName,
-    bg: ColorName,
-    sp: ColorName,
-    attr: HiAttr,
+pub struct HiCommand {
+    pub name: &'static str,
+    pub fg: ColorName,
+    pub bg: ColorName,
+    pub sp: ColorName,
+    pub attr: HiAttrs,
 }
 
 #[derive(Debug)]
-enum Highlight {
+pub enum Highlight {
     Fixed(HiCommand),
-    Dynamic { gui: HiCommand, term: HiCommand }, // Use different highlights for GUI and CUI
+    Dynamic {
+        gui: HiCommand,
+        term: HiCommand,
+    }, // Use different highlights for GUI and CUI
+    Link {
+        name: &'static str,
+        to: &'static str,
+    }, // Follow other highlight group
 }
 
-fn indent(level: u8) -> &'static str {
+pub fn indent(level: u8) -> &'static str {
     &"                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                "[..level as usize * 4]
 }
 
+// Arguments of `:hi` for attributes. A single attribute uses the `s:*_attr` variable as-is. Combined
+// attributes are joined into one `gui=` and one `cterm=` argument. Variables for combination end with
+// ',' and may be empty so the value ends with static attributes or `NONE`, which adds nothing.
+fn attr_args(attrs: HiAttrs) -> Vec<String> {
+    let mut iter = attrs.iter();
+    let (Some(attr), None) = (iter.next(), iter.next()) else {
+        if attrs.is_empty() {
+            return vec![];
+        }
+        return ["gui", "cterm"]
+            .into_iter()
+            .map(|item| {
+                let (vars, statics): (Vec<_>, Vec<_>) = attrs
+                    .iter()
+                    .map(|attr| attr_value(attr, item == "cterm"))
+                    .partition(|v| v.contains(':'));
+                let statics = if statics.is_empty() {
+                    "NONE".to_string()
+                } else {
+                    statics.join(",")
+                };
+                if vars.is_empty() {
+                    format!("{item}={statics}")
+                } else {
+                    format!("'{item}='.{}.'{statics}'", vars.join("."))
+                }
+            })
+            .collect();
+    };
+
+    let arg = match attr {
+        HiAttr::Nothing => return vec![],
+        HiAttr::Bold => "s:bold_attr",
+        HiAttr::Italic => "s:italic_attr",
+        HiAttr::Underline => "gui=underline cterm=underline",
+        HiAttr::Reverse => "gui=reverse cterm=reverse",
+        HiAttr::None => "gui=NONE cterm=NONE",
+        HiAttr::CommentItalic => "g:spring_night_italic_comments ? s:italic_attr : ''",
+        HiAttr::Undercurl => "s:undercurl_attr",
+        HiAttr::Strikethrough => "gui=strikethrough cterm=strikethrough",
+        HiAttr::Underdouble => "s:underdouble_attr",
+        HiAttr::Underdotted => "s:underdotted_attr",
+        HiAttr::Underdashed => "s:underdashed_attr",
+        HiAttr::Nocombine => "gui=nocombine cterm=nocombine",
+        HiAttr::Standout => "gui=standout cterm=standout",
+    };
+    vec![arg.into()]
+}
+
+// Value of an attribute in `gui=` or `cterm=` argument. Variables are used for attributes which
+// depend on options or environment.
+fn attr_value(attr: HiAttr, cterm: bool) -> &'static str {
+    match attr {
+        HiAttr::Nothing => "",
+        HiAttr::Bold => "s:bold",
+        HiAttr::Italic if cterm => "s:cterm_italic",
+        HiAttr::Italic => "s:italic",
+        HiAttr::CommentItalic if cterm => "(g:spring_night_italic_comments ? s:cterm_italic : '')",
+        HiAttr::CommentItalic => "(g:spring_night_italic_comments ? s:italic : '')",
+        HiAttr::Underline => "underline",
+        HiAttr::Reverse => "reverse",
+        HiAttr::None => "NONE",
+        HiAttr::Undercurl => "s:undercurl",
+        HiAttr::Strikethrough => "strikethrough",
+        HiAttr::Underdouble => "s:underdouble",
+        HiAttr::Underdotted => "s:underdotted",
+        HiAttr::Underdashed => "s:underdashed",
+        HiAttr::Nocombine => "nocombine",
+        HiAttr::Standout => "standout",
+    }
+}
+
 #[derive(Debug)]
 pub struct Colorscheme<'a> {
     palette: &'a Palette<'a>,
@@ -56,13 +232,32 @@ impl<'a> Colorscheme<'a> {
         }
 
         macro_rules! hi {
-            ($name:ident, $fg:tt, $bg:tt , $sp:tt, $attr:ident) => {
+            ($name:ident, $fg:tt, $bg:tt , $sp:tt, $($attr:ident)|+) => {
+                hi!(stringify!($name), $fg, $bg, $sp, $($attr)|+)
+            };
+            ($name:expr, $fg:tt, $bg:tt , $sp:tt, $($attr:ident)|+) => {
                 HiCommand {
-                    name: stringify!($name),
+                    name: $name,
                     fg: color!($fg),
                     bg: color!($bg),
                     sp: color!($sp),
-                    attr: HiAttr::$attr,
+                    // Bit operations instead of `|` operator of `HiAttr` keep the table constant
+                    attr: HiAttrs(0 $(| HiAttr::$attr as u16)+),
+                }
+            };
+        }
+
+        macro_rules! link {
+            ($name:ident, $to:ident) => {
+                link!(stringify!($name), stringify!($to))
+            };
+            ($name:expr, $to:ident) => {
+                link!($name, stringify!($to))
+            };
+            ($name:expr, $to:expr) => {
+                Highlight::Link {
+                    name: $name,
+                    to: $to,
                 }
             };
         }
@@ -74,204 +269,353 @@ impl<'a> Colorscheme<'a> {
             //        NAME                         FG          BG            SP      ATTRIBUTES
             //---------------------------------------------------------------------------------
             // Normal colors
-            Fixed(hi!(Boolean,                     red,        -,            -,      Nothing)),
-            Fixed(hi!(Character,                   green,      -,            -,      Nothing)),
-            Fixed(hi!(ColorColumn,                 -,          bgstrong,     -,      Nothing)),
-            Fixed(hi!(Comment,                     weakfg,     -,            -,      CommentItalic)),
-            Fixed(hi!(Conceal,                     mikan,      bg,           -,      Nothing)),
-            Fixed(hi!(Conditional,                 skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(Constant,                    red,        -,            -,      Nothing)),
-            Fixed(hi!(Cursor,                      bg,         fg,           -,      Nothing)),
-            Fixed(hi!(lCursor,                     bg,         fg,           -,      Nothing)),
-            Fixed(hi!(CursorColumn,                -,          bgemphasis,   -,      Nothing)),
-            Fixed(hi!(CursorLine,                  -,          bgemphasis,   -,      None)),
-            Fixed(hi!(CursorLineNr,                purple,     bgstrong,     -,      Nothing)),
-            Fixed(hi!(Define,                      orange,     -,            -,      Nothing)),
-            Fixed(hi!(Directory,                   green,      -,            -,      Nothing)),
-            Fixed(hi!(EndOfBuffer,                 bgstrong,   -,            -,      Nothing)),
-            Fixed(hi!(Error,                       red,        bgemphasis,   -,      Bold)),
-            Fixed(hi!(ErrorMsg,                    red,        bg,           -,      Bold)),
-            Fixed(hi!(Float,                       red,        -,            -,      Nothing)),
-            Fixed(hi!(NormalFloat,                 fg,         bgweaker,     -,      Nothing)),
-            Fixed(hi!(FloatBorder,                 weakfg,     bgweaker,     -,      Nothing)),
-            Fixed(hi!(FoldColumn,                  purple,     bgemphasis,   -,      Nothing)),
-            Fixed(hi!(Folded,                      purple,     light,        -,      Nothing)),
-            Fixed(hi!(Function,                    orange,     -,            -,      Nothing)),
-            Fixed(hi!(Identifier,                  gold,       -,            -,      Italic)),
-            Fixed(hi!(IncSearch,                   NONE,       sakura,       -,      Underline)),
-            Fixed(hi!(Keyword,                     yellow,     -,            -,      Bold)),
-            Fixed(hi!(Label,                       skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(LineNr,                      weakerfg,   bgemphasis,   -,      Nothing)),
-            Fixed(hi!(MatchParen,                  bg,         gold,         -,      Bold)),
-            Fixed(hi!(ModeMsg,                     gold,       -,            -,      Nothing)),
-            Fixed(hi!(MoreMsg,                     green,      -,            -,      Nothing)),
-            Fixed(hi!(NonText,                     light,      -,            -,      Nothing)),
-            Fixed(hi!(Normal,                      fg,         bg,           -,      Nothing)),
-            Fixed(hi!(Number,                      red,        -,            -,      Nothing)),
-            Fixed(hi!(Operater,                    orange,     -,            -,      Nothing)),
-            Fixed(hi!(Pmenu,                       purple,     bgemphasis,   -,      Nothing)),
-            Fixed(hi!(PmenuSbar,                   gold,       bgstrong,     -,      Nothing)),
-            Fixed(hi!(PmenuSel,                    gold,       bgstrong,     -,      Nothing)),
-            Fixed(hi!(PmenuThumb,                  gold,       weakfg,       -,      Nothing)),
-            Fixed(hi!(PreProc,                     orange,     -,            -,      Nothing)),
-            Fixed(hi!(Question,                    skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(Search,                      NONE,       nasu,         -,      Underline)),
-            Fixed(hi!(SignColumn,                  fg,         bgemphasis,   -,      Nothing)),
-            Fixed(hi!(Special,                     yellow,     -,            -,      Bold)),
-            Fixed(hi!(SpecialKey,                  hiddenfg,   -,            -,      Nothing)),
-            Fixed(hi!(SpecialComment,              palepink,   -,            -,      Nothing)),
+            Fixed(hi!(Boolean,                     red,        -,            -,       Nothing)),
+            Fixed(hi!(Character,                   green,      -,            -,       Nothing)),
+            Fixed(hi!(ColorColumn,                 -,          bgstrong,     -,       Nothing)),
+            Fixed(hi!(Comment,                     weakfg,     -,            -,       CommentItalic)),
+            Fixed(hi!(Conceal,                     mikan,      bg,           -,       Nothing)),
+            Fixed(hi!(Conditional,                 skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(Constant,                    red,        -,            -,       Nothing)),
+            Fixed(hi!(Cursor,                      bg,         fg,           -,       Nothing)),
+            link!(lCursor,                          Cursor),
+            Fixed(hi!(CursorColumn,                -,          bgemphasis,   -,       Nothing)),
+            Fixed(hi!(CursorLine,                  -,          bgemphasis,   -,       None)),
+            Fixed(hi!(CursorLineNr,                purple,     bgstrong,     -,       Nothing)),
+            Fixed(hi!(Define,                      orange,     -,            -,       Nothing)),
+            Fixed(hi!(Directory,                   green,      -,            -,       Nothing)),
+            Fixed(hi!(EndOfBuffer,                 bgstrong,   -,            -,       Nothing)),
+            Fixed(hi!(Error,                       red,        bgemphasis,   -,       Bold)),
+            Fixed(hi!(ErrorMsg,                    red,        bg,           -,       Bold)),
+            Fixed(hi!(Float,                       red,        -,            -,       Nothing)),
+            Fixed(hi!(NormalFloat,                 fg,         bgweaker,     -,       Nothing)),
+            Fixed(hi!(FloatBorder,                 weakfg,     bgweaker,     -,       Nothing)),
+            Fixed(hi!(FoldColumn,                  purple,     bgemphasis,   -,       Nothing)),
+            Fixed(hi!(Folded,                      purple,     light,        -,       Nothing)),
+            Fixed(hi!(Function,                    orange,     -,            -,       Nothing)),
+            Fixed(hi!(Identifier,                  gold,       -,            -,       Italic)),
+            Fixed(hi!(IncSearch,                   NONE,       sakura,       -,       Underline)),
+            Fixed(hi!(Keyword,                     yellow,     -,            -,       Bold)),
+            Fixed(hi!(Label,                       skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(LineNr,                      weakerfg,   bgemphasis,   -,       Nothing)),
+            Fixed(hi!(MatchParen,                  bg,         gold,         -,       Bold)),
+            Fixed(hi!(ModeMsg,                     gold,       -,            -,       Nothing)),
+            Fixed(hi!(MoreMsg,                     green,      -,            -,       Nothing)),
+            Fixed(hi!(NonText,                     light,      -,            -,       Nothing)),
+            Fixed(hi!(Normal,                      fg,         bg,           -,       Nothing)),
+            Fixed(hi!(Number,                      red,        -,            -,       Nothing)),
+            Fixed(hi!(Operator,                    orange,     -,            -,       Nothing)),
+            Fixed(hi!(Pmenu,                       purple,     bgemphasis,   -,       Nothing)),
+            Fixed(hi!(PmenuSbar,                   gold,       bgstrong,     -,       Nothing)),
+            Fixed(hi!(PmenuSel,                    gold,       bgstrong,     -,       Nothing)),
+            Fixed(hi!(PmenuThumb,                  gold,       weakfg,       -,       Nothing)),
+            Fixed(hi!(PreProc,                     orange,     -,            -,       Nothing)),
+            Fixed(hi!(Question,                    skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(Search,                      NONE,       nasu,         -,       Underline)),
+            Fixed(hi!(SignColumn,                  fg,         bgemphasis,   -,       Nothing)),
+            Fixed(hi!(Special,                     yellow,     -,            -,       Bold)),
+            Fixed(hi!(SpecialKey,                  hiddenfg,   -,            -,       Nothing)),
+            Fixed(hi!(SpecialComment,              palepink,   -,            -,       Nothing)),
             Dynamic {
-                gui:  hi!(SpellBad,                red,        -,            red,    Undercurl),
-                term: hi!(SpellBad,                red,        NONE,         red,    Undercurl),
+                gui:  hi!(SpellBad,                red,        -,            red,     Undercurl),
+                term: hi!(SpellBad,                red,        NONE,         red,     Undercurl),
             },
             Dynamic {
-                gui:  hi!(SpellCap,                purple,     -,            purple, Undercurl),
-                term: hi!(SpellCap,                purple,     NONE,         purple, Undercurl),
+                gui:  hi!(SpellCap,                purple,     -,            purple,  Undercurl),
+                term: hi!(SpellCap,                purple,     NONE,         purple,  Undercurl),
             },
             Dynamic {
-                gui:  hi!(SpellLocal,              red,        -,            red,    Undercurl),
-                term: hi!(SpellLocal,              red,        NONE,         red,    Undercurl),
+                gui:  hi!(SpellLocal,              red,        -,            red,     Undercurl),
+                term: hi!(SpellLocal,              red,        NONE,         red,     Undercurl),
             },
             Dynamic {
-                gui:  hi!(SpellRare,               yellow,     -,            yellow, Undercurl),
-                term: hi!(SpellRare,               yellow,     NONE,         yellow, Undercurl),
+                gui:  hi!(SpellRare,               yellow,     -,            yellow,  Undercurl),
+                term: hi!(SpellRare,               yellow,     NONE,         yellow,  Undercurl),
             },
-            Fixed(hi!(Statement,                   skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(StatusLine,                  fg,         bgstrong,     -,      Bold)),
-            Fixed(hi!(StatusLineNC,                weakfg,     bgemphasis,   -,      None)),
-            Fixed(hi!(StatusLineTerm,              fg,         bgstrong,     -,      Bold)),
-            Fixed(hi!(StatusLineTermNC,            weakfg,     bgemphasis,   -,      None)),
-            Fixed(hi!(StorageClass,                gold,       -,            -,      Italic)),
-            Fixed(hi!(String,                      green,      -,            -,      Nothing)),
-            Fixed(hi!(TabLine,                     weakfg,     bgstrong,     -,      Nothing)),
-            Fixed(hi!(TabLineFill,                 bgemphasis, -,            -,      Nothing)),
-            Fixed(hi!(TabLineSel,                  gold,       bg,           -,      Bold)),
-            Fixed(hi!(Tag,                         orange,     -,            -,      Nothing)),
-            Fixed(hi!(Title,                       gold,       -,            -,      Bold)),
-            Fixed(hi!(Todo,                        bg,         red,          -,      Bold)),
-            Fixed(hi!(ToolbarButton,               gold,       bg,           -,      Bold)),
-            Fixed(hi!(ToolbarLine,                 weakfg,     bgstrong,     -,      Nothing)),
-            Fixed(hi!(Type,                        gold,       -,            -,      Nothing)),
-            Fixed(hi!(Underlined,                  skyblue,    -,            -,      Underline)),
-            Fixed(hi!(VertSplit,                   bgemphasis, bg,           -,      Nothing)),
-            Fixed(hi!(Visual,                      -,          yaezakura,    -,      Nothing)),
-            Fixed(hi!(WarningMsg,                  mikan,      bgemphasis,   -,      Nothing)),
-            Fixed(hi!(WildMenu,                    bg,         gold,         -,      Nothing)),
+            Fixed(hi!(Statement,                   skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(StatusLine,                  fg,         bgstrong,     -,       Bold)),
+            Fixed(hi!(StatusLineNC,                weakfg,     bgemphasis,   -,       None)),
+            link!(StatusLineTerm,                   StatusLine),
+            link!(StatusLineTermNC,                 StatusLineNC),
+            Fixed(hi!(StorageClass,                gold,       -,            -,       Italic)),
+            Fixed(hi!(String,                      green,      -,            -,       Nothing)),
+            Fixed(hi!(TabLine,                     weakfg,     bgstrong,     -,       Nothing)),
+            Fixed(hi!(TabLineFill,                 bgemphasis, -,            -,       Nothing)),
+            Fixed(hi!(TabLineSel,                  gold,       bg,           -,       Bold)),
+            Fixed(hi!(Tag,                         orange,     -,            -,       Nothing)),
+            Fixed(hi!(Title,                       gold,       -,            -,       Bold)),
+            Fixed(hi!(Todo,                        bg,         red,          -,       Bold)),
+            Fixed(hi!(ToolbarButton,               gold,       bg,           -,       Bold)),
+            Fixed(hi!(ToolbarLine,                 weakfg,     bgstrong,     -,       Nothing)),
+            Fixed(hi!(Type,                        gold,       -,            -,       Nothing)),
+            Fixed(hi!(Underlined,                  skyblue,    -,            -,       Underline)),
+            Fixed(hi!(VertSplit,                   bgemphasis, bg,           -,       Nothing)),
+            Fixed(hi!(Visual,                      -,          yaezakura,    -,       Nothing)),
+            Fixed(hi!(WarningMsg,                  mikan,      bgemphasis,   -,       Nothing)),
+            Fixed(hi!(WildMenu,                    bg,         gold,         -,       Nothing)),
             //
             // File type specific
             //
             // Markdown is highlighted with H      TML highlights in gVim but link text doesn't
             // have a color. So define it her      e.
-            Fixed(hi!(cmakeArguments,              yellow,     -,            -,      Nothing)),
-            Fixed(hi!(cmakeOperators,              red,        -,            -,      Nothing)),
-            Fixed(hi!(cStorageClass,               yellow,     -,            -,      Nothing)),
-            Fixed(hi!(cTypedef,                    yellow,     -,            -,      Nothing)),
-            Fixed(hi!(DiffAdd,                     -,          darkgreen,    -,      Bold)),
-            Fixed(hi!(DiffChange,                  -,          darkgold,     -,      Bold)),
-            Fixed(hi!(DiffDelete,                  fg,         mildred,      -,      Bold)),
-            Fixed(hi!(DiffText,                    -,          bg,           -,      Nothing)),
-            Fixed(hi!(diffAdded,                   green,      -,            -,      Nothing)),
-            Fixed(hi!(diffFile,                    yellow,     -,            -,      Nothing)),
-            Fixed(hi!(diffIndexLine,               gold,       -,            -,      Nothing)),
-            Fixed(hi!(diffNewFile,                 yellow,     -,            -,      Nothing)),
-            Fixed(hi!(diffRemoved,                 red,        -,            -,      Nothing)),
-            Fixed(hi!(gitCommitOverflow,           -,          mildred,      -,      Nothing)),
-            Fixed(hi!(gitCommitSummary,            yellow,     -,            -,      Nothing)),
-            Fixed(hi!(gitCommitSelectedFile,       skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(gitconfigSection,            skyblue,    -,            -,      Bold)),
-            Fixed(hi!(glslQualifier,               yellow,     -,            -,      Nothing)),
-            Fixed(hi!(goBuiltins,                  red,        -,            -,      Nothing)),
-            Fixed(hi!(helpExample,                 skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(helpCommand,                 purple,     -,            -,      Nothing)),
-            Fixed(hi!(htmlBold,                    -,          bgemphasis,   -,      Nothing)),
-            Fixed(hi!(htmlLinkText,                skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(htmlTagName,                 orange,     -,            -,      Nothing)),
-            Fixed(hi!(javaScriptBraces,            fg,         -,            -,      Nothing)),
-            Fixed(hi!(makeCommands,                yellow,     -,            -,      Nothing)),
-            Fixed(hi!(manFooter,                   green,      -,            -,      Nothing)),
-            Fixed(hi!(manHeader,                   green,      -,            -,      Nothing)),
-            Fixed(hi!(manLongOptionDesc,           yellow,     -,            -,      Nothing)),
-            Fixed(hi!(manOptionDesc,               yellow,     -,            -,      Nothing)),
-            Fixed(hi!(manReference,                skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(manSectionHeading,           gold,       -,            -,      Nothing)),
-            Fixed(hi!(manSubHeading,               orange,     -,            -,      Nothing)),
-            Fixed(hi!(markdownCode,                yellow,     -,            -,      Nothing)),
-            Fixed(hi!(markdownUrl,                 weakfg,     -,            -,      Nothing)),
-            Fixed(hi!(ocamlConstructor,            gold,       -,            -,      Nothing)),
-            Fixed(hi!(ocamlKeyChar,                skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(ocamlKeyword,                gold   ,    -,            -,      Nothing)),
-            Fixed(hi!(ocamlFunDef,                 skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(plantumlColonLine,           skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(pythonBuiltin,               red,        -,            -,      Nothing)),
-            Fixed(hi!(qfFileName,                  gold,       -,            -,      Nothing)),
-            Fixed(hi!(qfLineNr,                    skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(rstEmphasis,                 -,          bgemphasis,   -,      Italic)),
-            Fixed(hi!(rstStrongEmphasis,           -,          bgstrong,     -,      Bold)),
-            Fixed(hi!(rubyFunction,                yellow,     -,            -,      Nothing)),
-            Fixed(hi!(rubyIdentifier,              yellow,     -,            -,      Nothing)),
-            Fixed(hi!(rustEnumVariant,             gold,       -,            -,      Nothing)),
-            Fixed(hi!(rustFuncCall,                fg,         -,            -,      Nothing)),
-            Fixed(hi!(rustCommentLineDoc,          palepink,   -,            -,      Nothing)),
-            Fixed(hi!(scalaInstanceDeclaration,    gold,       -,            -,      Nothing)),
-            Fixed(hi!(tomlTable,                   skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(tomlTableArray,              skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(tomlKey,                     gold,       -,            -,      Nothing)),
-            Fixed(hi!(tmuxCommands,                skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(tmuxFlags,                   gold,       -,            -,      Nothing)),
-            Fixed(hi!(tmuxFormatString,            yellow,     -,            -,      Nothing)),
-            Fixed(hi!(typescriptBraces,            fg,         -,            -,      Nothing)),
-            Fixed(hi!(typescriptAsyncFuncKeyword,  skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(typescriptKeywordOp,         yellow,     -,            -,      Bold)),
-            Fixed(hi!(vimfilerColumn__SizeLine,    weakfg,     -,            -,      Nothing)),
-            Fixed(hi!(vimfilerClosedFile,          green,      -,            -,      Nothing)),
-            Fixed(hi!(vimCommand,                  skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(watListDelimiter,            fg,         -,            -,      Nothing)),
-            Fixed(hi!(watInstGeneral,              yellow,     -,            -,      Nothing)),
-            Fixed(hi!(watInstGetSet,               yellow,     -,            -,      Nothing)),
-            Fixed(hi!(watInstWithType,             yellow,     -,            -,      Nothing)),
-            Fixed(hi!(watUnnamedVar,               purple,     -,            -,      Nothing)),
-            Fixed(hi!(zshDelimiter,                skyblue,    -,            -,      Nothing)),
-            Fixed(hi!(zshPrecommand,               red,        -,            -,      Nothing)),
-            Fixed(hi!(debugPC,                     bg,         skyblue,      -,      Nothing)),
-            Fixed(hi!(debugBreakPoint,             bg,         gold,         -,      Nothing)),
-            Fixed(hi!(zigMultilineStringDelimiter, yellow,     -,            -,      Nothing)),
+            Fixed(hi!(cmakeArguments,              yellow,     -,            -,       Nothing)),
+            Fixed(hi!(cmakeOperators,              red,        -,            -,       Nothing)),
+            Fixed(hi!(cStorageClass,               yellow,     -,            -,       Nothing)),
+            Fixed(hi!(cTypedef,                    yellow,     -,            -,       Nothing)),
+            Fixed(hi!(DiffAdd,                     -,          darkgreen,    -,       Bold)),
+            Fixed(hi!(DiffChange,                  -,          darkgold,     -,       Bold)),
+            Fixed(hi!(DiffDelete,                  fg,         mildred,      -,       Bold)),
+            Fixed(hi!(DiffText,                    -,          bg,           -,       Nothing)),
+            Fixed(hi!(diffAdded,                   green,      -,            -,       Nothing)),
+            Fixed(hi!(diffFile,                    yellow,     -,            -,       Nothing)),
+            Fixed(hi!(diffIndexLine,               gold,       -,            -,       Nothing)),
+            Fixed(hi!(diffNewFile,                 yellow,     -,            -,       Nothing)),
+            Fixed(hi!(diffRemoved,                 red,        -,            -,       Nothing)),
+            Fixed(hi!(gitcommitOverflow,           -,          mildred,      -,       Nothing)),
+            Fixed(hi!(gitcommitSummary,            yellow,     -,            -,       Nothing)),
+            Fixed(hi!(gitcommitSelectedFile,       skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(gitconfigSection,            skyblue,    -,            -,       Bold)),
+            Fixed(hi!(glslQualifier,               yellow,     -,            -,       Nothing)),
+            Fixed(hi!(goBuiltins,                  red,        -,            -,       Nothing)),
+            Fixed(hi!(helpExample,                 skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(helpCommand,                 purple,     -,            -,       Nothing)),
+            Fixed(hi!(htmlBold,                    -,          bgemphasis,   -,       Nothing)),
+            Fixed(hi!(htmlLinkText,                skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(htmlTagName,                 orange,     -,            -,       Nothing)),
+            Fixed(hi!(javaScriptBraces,            fg,         -,            -,       Nothing)),
+            Fixed(hi!(makeCommands,                yellow,     -,            -,       Nothing)),
+            Fixed(hi!(manFooter,                   green,      -,            -,       Nothing)),
+            Fixed(hi!(manHeader,                   green,      -,            -,       Nothing)),
+            Fixed(hi!(manLongOptionDesc,           yellow,     -,            -,       Nothing)),
+            Fixed(hi!(manOptionDesc,               yellow,     -,            -,       Nothing)),
+            Fixed(hi!(manReference,                skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(manSectionHeading,           gold,       -,            -,       Nothing)),
+            Fixed(hi!(manSubHeading,               orange,     -,            -,       Nothing)),
+            Fixed(hi!(markdownCode,                yellow,     -,            -,       Nothing)),
+            Fixed(hi!(markdownUrl,                 weakfg,     -,            -,       Nothing)),
+            Fixed(hi!(ocamlConstructor,            gold,       -,            -,       Nothing)),
+            Fixed(hi!(ocamlKeyChar,                skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(ocamlKeyword,                gold   ,    -,            -,       Nothing)),
+            Fixed(hi!(ocamlFunDef,                 skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(plantumlColonLine,           skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(pythonBuiltin,               red,        -,            -,       Nothing)),
+            Fixed(hi!(qfFileName,                  gold,       -,            -,       Nothing)),
+            Fixed(hi!(qfLineNr,                    skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(rstEmphasis,                 -,          bgemphasis,   -,       Italic)),
+            Fixed(hi!(rstStrongEmphasis,           -,          bgstrong,     -,       Bold)),
+            Fixed(hi!(rubyFunction,                yellow,     -,            -,       Nothing)),
+            Fixed(hi!(rubyIdentifier,              yellow,     -,            -,       Nothing)),
+            Fixed(hi!(rustEnumVariant,             gold,       -,            -,       Nothing)),
+            Fixed(hi!(rustFuncCall,                fg,         -,            -,       Nothing)),
+            Fixed(hi!(rustCommentLineDoc,          palepink,   -,            -,       Nothing)),
+            Fixed(hi!(scalaInstanceDeclaration,    gold,       -,            -,       Nothing)),
+            Fixed(hi!(tomlTable,                   skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(tomlTableArray,              skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(tomlKey,                     gold,       -,            -,       Nothing)),
+            Fixed(hi!(tmuxCommands,                skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(tmuxFlags,                   gold,       -,            -,       Nothing)),
+            Fixed(hi!(tmuxFormatString,            yellow,     -,            -,       Nothing)),
+            Fixed(hi!(typescriptBraces,            fg,         -,            -,       Nothing)),
+            Fixed(hi!(typescriptAsyncFuncKeyword,  skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(typescriptKeywordOp,         yellow,     -,            -,       Bold)),
+            Fixed(hi!(vimfilerColumn__SizeLine,    weakfg,     -,            -,       Nothing)),
+            Fixed(hi!(vimfilerClosedFile,          green,      -,            -,       Nothing)),
+            Fixed(hi!(vimCommand,                  skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(watListDelimiter,            fg,         -,            -,       Nothing)),
+            Fixed(hi!(watInstGeneral,              yellow,     -,            -,       Nothing)),
+            Fixed(hi!(watInstGetSet,               yellow,     -,            -,       Nothing)),
+            Fixed(hi!(watInstWithType,             yellow,     -,            -,       Nothing)),
+            Fixed(hi!(watUnnamedVar,               purple,     -,            -,       Nothing)),
+            Fixed(hi!(zshDelimiter,                skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(zshPrecommand,               red,        -,            -,       Nothing)),
+            Fixed(hi!(debugPC,                     bg,         skyblue,      -,       Nothing)),
+            Fixed(hi!(debugBreakpoint,             bg,         gold,         -,       Nothing)),
+            Fixed(hi!(zigMultilineStringDelimiter, yellow,     -,            -,       Nothing)),
             //
             // Plugin specific
             //
             // Some plugins introduce its own highlight definitions. Adjust them for
             // working fine with this colorscheme.
-            Fixed(hi!(ALEWarningSign,              orange,     bgemphasis,   -,      Bold)),
-            Fixed(hi!(ALEErrorSign,                bgemphasis, mildred,      -,      Bold)),
-            Fixed(hi!(ALEInfoSign,                 -,          light,        -,      Nothing)),
-            Fixed(hi!(ALEError,                    -,          mildred,      -,      Nothing)),
-            Fixed(hi!(ALEWarning,                  -,          darkgold,     -,      Nothing)),
-            Fixed(hi!(Flake8_Error,                red,        bgemphasis,   -,      Nothing)),
-            Fixed(hi!(Flake8_Warning,              yellow,     bgemphasis,   -,      Nothing)),
-            Fixed(hi!(Flake8_PyFlake,              skyblue,    bgemphasis,   -,      Nothing)),
-            Fixed(hi!(Flake8_Complexity,           skyblue,    bgemphasis,   -,      Nothing)),
-            Fixed(hi!(Flake8_Naming,               skyblue,    bgemphasis,   -,      Nothing)),
-            Fixed(hi!(SignifySignAdd,              green,      bgemphasis,   -,      Nothing)),
-            Fixed(hi!(SignifySignChange,           yellow,     bgemphasis,   -,      Nothing)),
-            Fixed(hi!(SignifySignChangeDelete,     gold,       bgemphasis,   -,      Nothing)),
-            Fixed(hi!(SignifySignDelete,           red,        bgemphasis,   -,      Nothing)),
-            Fixed(hi!(CleverFChar,                 bg,         red,          -,      Nothing)),
-            Fixed(hi!(CleverFDirect,               bg,         red,          -,      Nothing)),
-            Fixed(hi!(DirvishArg,                  yellow,     -,            -,      Bold)),
-            Fixed(hi!(EasyMotionTarget,            red,        -,            -,      Bold)),
-            Fixed(hi!(EasyMotionShade,             weakfg,     bg,           -,      Nothing)),
-            Fixed(hi!(GitGutterAdd,                green,      bgemphasis,   -,      Nothing)),
-            Fixed(hi!(GitGutterChange,             yellow,     bgemphasis,   -,      Nothing)),
-            Fixed(hi!(GitGutterChangeDelete,       gold,       bgemphasis,   -,      Nothing)),
-            Fixed(hi!(GitGutterDelete,             red,        bgemphasis,   -,      Nothing)),
-            Fixed(hi!(HighlightedyankRegion,       -,          bgemphasis,   -,      Nothing)),
+            link!(ALEWarningSign,                   DiagnosticSignWarn),
+            link!(ALEErrorSign,                     DiagnosticSignError),
+            Fixed(hi!(ALEInfoSign,                 -,          light,        -,       Nothing)),
+            link!(ALEError,                         DiagnosticUnderlineError),
+            link!(ALEWarning,                       DiagnosticUnderlineWarn),
+            Fixed(hi!(Flake8_Error,                red,        bgemphasis,   -,       Nothing)),
+            Fixed(hi!(Flake8_Warning,              yellow,     bgemphasis,   -,       Nothing)),
+            Fixed(hi!(Flake8_PyFlake,              skyblue,    bgemphasis,   -,       Nothing)),
+            Fixed(hi!(Flake8_Complexity,           skyblue,    bgemphasis,   -,       Nothing)),
+            Fixed(hi!(Flake8_Naming,               skyblue,    bgemphasis,   -,       Nothing)),
+            Fixed(hi!(SignifySignAdd,              green,      bgemphasis,   -,       Nothing)),
+            Fixed(hi!(SignifySignChange,           yellow,     bgemphasis,   -,       Nothing)),
+            Fixed(hi!(SignifySignChangeDelete,     gold,       bgemphasis,   -,       Nothing)),
+            Fixed(hi!(SignifySignDelete,           red,        bgemphasis,   -,       Nothing)),
+            Fixed(hi!(CleverFChar,                 bg,         red,          -,       Nothing)),
+            Fixed(hi!(CleverFDirect,               bg,         red,          -,       Nothing)),
+            Fixed(hi!(DirvishArg,                  yellow,     -,            -,       Bold)),
+            Fixed(hi!(EasyMotionTarget,            red,        -,            -,       Bold)),
+            Fixed(hi!(EasyMotionShade,             weakfg,     bg,           -,       Nothing)),
+            link!(GitGutterAdd,                     SignifySignAdd),
+            link!(GitGutterChange,                  SignifySignChange),
+            link!(GitGutterChangeDelete,            SignifySignChangeDelete),
+            link!(GitGutterDelete,                  SignifySignDelete),
+            Fixed(hi!(HighlightedyankRegion,       -,          bgemphasis,   -,       Nothing)),
             Dynamic {
-                gui:  hi!(EasyMotionIncCursor,     bg,         fg,           -,      Nothing),
-                term: hi!(EasyMotionIncCursor,     -,          -,            -,      Reverse),
+                gui:  hi!(EasyMotionIncCursor,     bg,         fg,           -,       Nothing),
+                term: hi!(EasyMotionIncCursor,     -,          -,            -,       Reverse),
             },
-            Fixed(hi!(plugDeleted,                 weakfg,     -,            -,      Nothing)),
-            Fixed(hi!(ConflictMarker,              -,          mildred,      -,      Nothing)),
-            Fixed(hi!(IndentGuidesOdd,             -,          bgweaker,     -,      Nothing)),
-            Fixed(hi!(IndentGuidesEven,            -,          bgemphasis,   -,      Nothing)),
+            Fixed(hi!(plugDeleted,                 weakfg,     -,            -,       Nothing)),
+            Fixed(hi!(ConflictMarker,              -,          mildred,      -,       Nothing)),
+            Fixed(hi!(IndentGuidesOdd,             -,          bgweaker,     -,       Nothing)),
+            Fixed(hi!(IndentGuidesEven,            -,          bgemphasis,   -,       Nothing)),
+            //
+            // LSP
+            //
+            // Diagnostics of Neovim's builtin LSP client and Vim's LSP plugins. ALE's highlights are
+            // linked to them. Semantic tokens (@lsp.*) follow the corresponding tree-sitter captures.
+            Fixed(hi!(DiagnosticError,             red,        -,            -,       Nothing)),
+            Fixed(hi!(DiagnosticWarn,              mikan,      -,            -,       Nothing)),
+            Fixed(hi!(DiagnosticInfo,              skyblue,    -,            -,       Nothing)),
+            Fixed(hi!(DiagnosticHint,              weakfg,     -,            -,       Nothing)),
+            Fixed(hi!(DiagnosticOk,                green,      -,            -,       Nothing)),
+            link!(DiagnosticVirtualTextError,       DiagnosticError),
+            link!(DiagnosticVirtualTextWarn,        DiagnosticWarn),
+            link!(DiagnosticVirtualTextInfo,        DiagnosticInfo),
+            link!(DiagnosticVirtualTextHint,        DiagnosticHint),
+            link!(DiagnosticVirtualTextOk,          DiagnosticOk),
+            Fixed(hi!(DiagnosticUnderlineError,    -,          mildred,      -,       Nothing)),
+            Fixed(hi!(DiagnosticUnderlineWarn,     -,          darkgold,     -,       Nothing)),
+            Fixed(hi!(DiagnosticUnderlineInfo,     -,          -,            skyblue, Undercurl)),
+            Fixed(hi!(DiagnosticUnderlineHint,     -,          -,            weakfg,  Underdashed)),
+            Fixed(hi!(DiagnosticUnderlineOk,       -,          -,            green,   Undercurl)),
+            Fixed(hi!(DiagnosticSignError,         bgemphasis, mildred,      -,       Bold)),
+            Fixed(hi!(DiagnosticSignWarn,          orange,     bgemphasis,   -,       Bold)),
+            Fixed(hi!(DiagnosticSignInfo,          skyblue,    bgemphasis,   -,       Nothing)),
+            Fixed(hi!(DiagnosticSignHint,          weakfg,     bgemphasis,   -,       Nothing)),
+            Fixed(hi!(DiagnosticSignOk,            green,      bgemphasis,   -,       Nothing)),
+            Fixed(hi!(DiagnosticFloatingError,     red,        bgweaker,     -,       Nothing)),
+            Fixed(hi!(DiagnosticFloatingWarn,      mikan,      bgweaker,     -,       Nothing)),
+            Fixed(hi!(DiagnosticFloatingInfo,      skyblue,    bgweaker,     -,       Nothing)),
+            Fixed(hi!(DiagnosticFloatingHint,      weakfg,     bgweaker,     -,       Nothing)),
+            Fixed(hi!(DiagnosticFloatingOk,        green,      bgweaker,     -,       Nothing)),
+            Fixed(hi!(DiagnosticUnnecessary,       weakfg,     -,            -,       Nothing)),
+            Fixed(hi!(DiagnosticDeprecated,        weakfg,     -,            -,       Strikethrough)),
+            Fixed(hi!(LspReferenceText,            -,          bgemphasis,   -,       Nothing)),
+            link!(LspReferenceRead,                 LspReferenceText),
+            Fixed(hi!(LspReferenceWrite,           -,          bgstrong,     -,       Nothing)),
+            Fixed(hi!(LspInlayHint,                weakerfg,   bgweaker,     -,       Nothing)),
+            Fixed(hi!(LspCodeLens,                 weakerfg,   -,            -,       Nothing)),
+            Fixed(hi!(LspCodeLensSeparator,        hiddenfg,   -,            -,       Nothing)),
+            Fixed(hi!(LspSignatureActiveParameter, gold,       -,            -,       Bold)),
+            link!("@lsp.type.class",                "@type"),
+            link!("@lsp.type.comment",              "@comment"),
+            link!("@lsp.type.decorator",            "@attribute"),
+            link!("@lsp.type.enum",                 "@type"),
+            link!("@lsp.type.enumMember",           "@constructor"),
+            link!("@lsp.type.function",             "@function"),
+            link!("@lsp.type.interface",            "@type"),
+            link!("@lsp.type.keyword",              "@keyword"),
+            link!("@lsp.type.macro",                "@function.macro"),
+            link!("@lsp.type.method",               "@function.method"),
+            link!("@lsp.type.namespace",            "@module"),
+            link!("@lsp.type.number",               "@number"),
+            link!("@lsp.type.operator",             "@operator"),
+            link!("@lsp.type.parameter",            "@variable.parameter"),
+            link!("@lsp.type.property",             "@property"),
+            link!("@lsp.type.string",               "@string"),
+            link!("@lsp.type.struct",               "@type"),
+            link!("@lsp.type.type",                 "@type"),
+            link!("@lsp.type.typeParameter",        "@type.definition"),
+            link!("@lsp.type.variable",             "@variable"),
+            Fixed(hi!("@lsp.mod.deprecated",       weakfg,     -,            -,       Strikethrough)),
+            link!("@lsp.typemod.function.defaultLibrary", "@function.builtin"),
+            link!("@lsp.typemod.variable.defaultLibrary", "@variable.builtin"),
+            //
+            // Tree-sitter captures (Neovim)
+            //
+            // Neovim highlights syntax with these captures when tree-sitter is enabled. They
+            // follow the colors of the corresponding Vim syntax groups.
+            Fixed(hi!("@variable",                 fg,         -,            -,       Nothing)),
+            Fixed(hi!("@variable.builtin",         red,        -,            -,       Nothing)),
+            Fixed(hi!("@variable.parameter",       fg,         -,            -,       Nothing)),
+            Fixed(hi!("@variable.member",          fg,         -,            -,       Nothing)),
+            link!("@constant",                      Constant),
+            Fixed(hi!("@constant.builtin",         red,        -,            -,       Nothing)),
+            Fixed(hi!("@constant.macro",           orange,     -,            -,       Nothing)),
+            Fixed(hi!("@module",                   gold,       -,            -,       Nothing)),
+            Fixed(hi!("@module.builtin",           red,        -,            -,       Nothing)),
+            link!("@label",                         Label),
+            link!("@string",                        String),
+            Fixed(hi!("@string.documentation",     palepink,   -,            -,       Nothing)),
+            Fixed(hi!("@string.regexp",            yellow,     -,            -,       Nothing)),
+            Fixed(hi!("@string.escape",            yellow,     -,            -,       Bold)),
+            Fixed(hi!("@string.special",           yellow,     -,            -,       Nothing)),
+            Fixed(hi!("@string.special.symbol",    red,        -,            -,       Nothing)),
+            Fixed(hi!("@string.special.url",       skyblue,    -,            -,       Underline)),
+            link!("@character",                     Character),
+            Fixed(hi!("@character.special",        yellow,     -,            -,       Bold)),
+            link!("@boolean",                       Boolean),
+            link!("@number",                        Number),
+            link!("@number.float",                  Float),
+            link!("@type",                          Type),
+            Fixed(hi!("@type.builtin",             gold,       -,            -,       Nothing)),
+            Fixed(hi!("@type.definition",          gold,       -,            -,       Nothing)),
+            Fixed(hi!("@attribute",                orange,     -,            -,       Nothing)),
+            Fixed(hi!("@attribute.builtin",        orange,     -,            -,       Nothing)),
+            Fixed(hi!("@property",                 fg,         -,            -,       Nothing)),
+            link!("@function",                      Function),
+            Fixed(hi!("@function.builtin",         red,        -,            -,       Nothing)),
+            Fixed(hi!("@function.call",            fg,         -,            -,       Nothing)),
+            Fixed(hi!("@function.macro",           orange,     -,            -,       Nothing)),
+            Fixed(hi!("@function.method",          orange,     -,            -,       Nothing)),
+            Fixed(hi!("@function.method.call",     fg,         -,            -,       Nothing)),
+            Fixed(hi!("@constructor",              gold,       -,            -,       Nothing)),
+            Fixed(hi!("@operator",                 orange,     -,            -,       Nothing)),
+            link!("@keyword",                       Keyword),
+            Fixed(hi!("@keyword.coroutine",        skyblue,    -,            -,       Nothing)),
+            Fixed(hi!("@keyword.function",         yellow,     -,            -,       Bold)),
+            Fixed(hi!("@keyword.operator",         yellow,     -,            -,       Bold)),
+            Fixed(hi!("@keyword.import",           orange,     -,            -,       Nothing)),
+            Fixed(hi!("@keyword.type",             gold,       -,            -,       Italic)),
+            Fixed(hi!("@keyword.modifier",         gold,       -,            -,       Italic)),
+            Fixed(hi!("@keyword.repeat",           skyblue,    -,            -,       Nothing)),
+            Fixed(hi!("@keyword.return",           skyblue,    -,            -,       Nothing)),
+            Fixed(hi!("@keyword.debug",            red,        -,            -,       Nothing)),
+            Fixed(hi!("@keyword.exception",        skyblue,    -,            -,       Nothing)),
+            Fixed(hi!("@keyword.conditional",      skyblue,    -,            -,       Nothing)),
+            link!("@keyword.conditional.ternary",   "@operator"),
+            Fixed(hi!("@keyword.directive",        orange,     -,            -,       Nothing)),
+            Fixed(hi!("@keyword.directive.define", orange,     -,            -,       Nothing)),
+            Fixed(hi!("@punctuation.delimiter",    fg,         -,            -,       Nothing)),
+            Fixed(hi!("@punctuation.bracket",      fg,         -,            -,       Nothing)),
+            Fixed(hi!("@punctuation.special",      yellow,     -,            -,       Bold)),
+            link!("@comment",                       Comment),
+            Fixed(hi!("@comment.documentation",    palepink,   -,            -,       Nothing)),
+            Fixed(hi!("@comment.error",            bg,         red,          -,       Bold)),
+            Fixed(hi!("@comment.warning",          bg,         mikan,        -,       Bold)),
+            Fixed(hi!("@comment.todo",             bg,         red,          -,       Bold)),
+            Fixed(hi!("@comment.note",             bg,         skyblue,      -,       Bold)),
+            Fixed(hi!("@markup.strong",            -,          -,            -,       Bold)),
+            Fixed(hi!("@markup.italic",            -,          -,            -,       Italic)),
+            Fixed(hi!("@markup.underline",         -,          -,            -,       Underline)),
+            Fixed(hi!("@markup.strikethrough",     -,          -,            -,       Strikethrough)),
+            Fixed(hi!("@markup.heading",           gold,       -,            -,       Bold)),
+            Fixed(hi!("@markup.quote",             weakfg,     -,            -,       Nothing)),
+            Fixed(hi!("@markup.math",              yellow,     -,            -,       Nothing)),
+            Fixed(hi!("@markup.link",              skyblue,    -,            -,       Nothing)),
+            Fixed(hi!("@markup.link.label",        skyblue,    -,            -,       Nothing)),
+            Fixed(hi!("@markup.link.url",          weakfg,     -,            -,       Underline)),
+            Fixed(hi!("@markup.raw",               yellow,     -,            -,       Nothing)),
+            Fixed(hi!("@markup.raw.block",         yellow,     -,            -,       Nothing)),
+            Fixed(hi!("@markup.list",              skyblue,    -,            -,       Nothing)),
+            Fixed(hi!("@markup.list.checked",      green,      -,            -,       Nothing)),
+            Fixed(hi!("@markup.list.unchecked",    weakfg,     -,            -,       Nothing)),
+            Fixed(hi!("@diff.plus",                green,      -,            -,       Nothing)),
+            Fixed(hi!("@diff.minus",               red,        -,            -,       Nothing)),
+            Fixed(hi!("@diff.delta",               gold,       -,            -,       Nothing)),
+            Fixed(hi!("@tag",                      orange,     -,            -,       Nothing)),
+            Fixed(hi!("@tag.builtin",              orange,     -,            -,       Nothing)),
+            Fixed(hi!("@tag.attribute",            gold,       -,            -,       Nothing)),
+            Fixed(hi!("@tag.delimiter",            weakfg,     -,            -,       Nothing)),
         ];
 
         let term_colors = [
@@ -300,10 +644,11 @@ impl<'a> Colorscheme<'a> {
         }
     }
 
-    fn write_header(&self, w: &mut impl Write) -> Result<()> {
+    fn write_header(&self, w: &mut dyn Write) -> Result<()> {
+        let variant = self.palette.variant();
         write!(
             w,
-            r#"" spring-night: Calm-colored dark color scheme
+            r#"" {name}: Calm-colored {background} color scheme
 "
 " Author: rhysd <lin90162@yahoo.co.jp>
 " License: MIT
@@ -312,16 +657,23 @@ impl<'a> Colorscheme<'a> {
 " PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
 " Generated by script vim-color-spring-night/gen/{source}
 
+" Neovim loads the Lua version of this colorscheme which sets highlights with
+" nvim_set_hl() instead of building `:hi` commands with `:execute`.
+if has('nvim-0.8')
+    execute 'luafile' fnameescape(expand('<sfile>:p:r') . '.lua')
+    finish
+endif
+
 " Optimization:
-" `:set background=dark` has some side effects which takes a time.
-" Avoid the side effects when the value is already 'dark'.
-if &background !=# 'dark'
-    set background=dark
+" `:set background={background}` has some side effects which takes a time.
+" Avoid the side effects when the value is already '{background}'.
+if &background !=# '{background}'
+    set background={background}
 endif
 
 " Optimization:
 " `:hi clear` takes a lot of time since it clears all highlights and set default
-" highlights. This guard avoids `:hi clear` if spring-night is the first colorscheme.
+" highlights. This guard avoids `:hi clear` if {name} is the first colorscheme.
 " applied in vimrc. In almost all cases no additional highlights are set at start
 " up since they are set by Vim plugins.
 if exists('g:colors_name')
@@ -333,7 +685,7 @@ if exists('g:syntax_on')
     syntax reset
 endif
 
-let g:colors_name = 'spring-night'
+let g:colors_name = '{name}'
 
 let g:spring_night_italic_comments = get(g:, 'spring_night_italic_comments', 0)
 let g:spring_night_kill_italic = get(g:, 'spring_night_kill_italic', 0)
@@ -346,6 +698,19 @@ let s:true_colors = has('termguicolors') && &termguicolors
 let s:undercurl_attr = s:gui_running ? 'gui=undercurl cterm=undercurl' : 'gui=underline cterm=underline'
 let s:italic_attr = g:spring_night_kill_italic ? '' : g:spring_night_cterm_italic ? 'gui=italic cterm=italic' : 'gui=italic'
 let s:bold_attr = g:spring_night_kill_bold ? '' : 'gui=bold cterm=bold'
+" underdouble, underdotted and underdashed are available since Vim 9.0
+let s:fancy_underline = s:gui_running && v:version >= 900
+let s:underdouble_attr = s:fancy_underline ? 'gui=underdouble cterm=underdouble' : 'gui=underline cterm=underline'
+let s:underdotted_attr = s:fancy_underline ? 'gui=underdotted cterm=underdotted' : 'gui=underline cterm=underline'
+let s:underdashed_attr = s:fancy_underline ? 'gui=underdashed cterm=underdashed' : 'gui=underline cterm=underline'
+" Attributes for combining them in one `gui=` or `cterm=` argument
+let s:bold = g:spring_night_kill_bold ? '' : 'bold,'
+let s:italic = g:spring_night_kill_italic ? '' : 'italic,'
+let s:cterm_italic = g:spring_night_cterm_italic ? s:italic : ''
+let s:undercurl = s:gui_running ? 'undercurl,' : 'underline,'
+let s:underdouble = s:fancy_underline ? 'underdouble,' : 'underline,'
+let s:underdotted = s:fancy_underline ? 'underdotted,' : 'underline,'
+let s:underdashed = s:fancy_underline ? 'underdashed,' : 'underline,'
 
 if exists('g:spring_night_high_contrast')
     if type(g:spring_night_high_contrast) != type(0)
@@ -357,11 +722,13 @@ else
 endif
 
 "#,
+            name = variant.name(),
+            background = variant.background(),
             source = file!(),
         )
     }
 
-    fn write_contrast_color_variables(&self, w: &mut impl Write) -> Result<()> {
+    fn write_contrast_color_variables(&self, w: &mut dyn Write) -> Result<()> {
         for (name, color) in {
             let mut v = self.palette.iter().collect::<Vec<_>>();
             v.sort_by_key(|(&k, _)| k); // Sort by color name to avoid random order
@@ -383,7 +750,7 @@ endif
         writeln!(w)
     }
 
-    fn write_hi_command(&self, w: &mut impl Write, cmd: &HiCommand, indents: u8) -> Result<()> {
+    fn write_hi_command(&self, w: &mut dyn Write, cmd: &HiCommand, indents: u8) -> Result<()> {
         fn arg(name: &str, item: &str, color: &ColorCode<impl Display>) -> String {
             match color {
                 ColorCode::Normal(c) => format!("{item}={c}"),
@@ -417,19 +784,7 @@ endif
             args.push(arg(name, "guisp", color));
         }
 
-        let attr_item = match cmd.attr {
-            HiAttr::Nothing => "",
-            HiAttr::Bold => "s:bold_attr",
-            HiAttr::Italic => "s:italic_attr",
-            HiAttr::Underline => "gui=underline cterm=underline",
-            HiAttr::Reverse => "gui=reverse cterm=reverse",
-            HiAttr::None => "gui=NONE cterm=NONE",
-            HiAttr::CommentItalic => "g:spring_night_italic_comments ? s:italic_attr : ''",
-            HiAttr::Undercurl => "s:undercurl_attr",
-        };
-        if !attr_item.is_empty() {
-            args.push(attr_item.into());
-        }
+        args.extend(attr_args(cmd.attr));
 
         let is_execute = args.iter().any(|a| a.contains("s:") || a.contains("g:"));
         if is_execute {
@@ -449,10 +804,14 @@ endif
         writeln!(w)
     }
 
-    fn write_highlights(&self, w: &mut impl Write) -> Result<()> {
+    fn write_highlights(&self, w: &mut dyn Write) -> Result<()> {
         for hl in self.highlights {
             match hl {
+                // Tree-sitter captures are only for Neovim, which loads the Lua colorscheme
+                Highlight::Fixed(hl) if hl.name.starts_with('@') => {}
+                Highlight::Link { name, .. } if name.starts_with('@') => {}
                 Highlight::Fixed(hl) => self.write_hi_command(w, hl, 0)?,
+                Highlight::Link { name, to } => writeln!(w, "hi! link {name} {to}")?,
                 Highlight::Dynamic { gui, term } => {
                     writeln!(w, "if s:gui_running")?;
                     self.write_hi_command(w, gui, 1)?;
@@ -465,7 +824,7 @@ endif
         writeln!(w)
     }
 
-    fn write_nvim_term_colors(&self, w: &mut impl Write, indents: u8) -> Result<()> {
+    fn write_nvim_term_colors(&self, w: &mut dyn Write, indents: u8) -> Result<()> {
         writeln!(w, "{}if s:gui_running || s:true_colors", indent(indents))?;
         for (index, name) in self.term_colors.iter().enumerate() {
             writeln!(
@@ -497,7 +856,7 @@ endif
         )
     }
 
-    fn write_vim_term_colors(&self, w: &mut impl Write, indents: u8) -> Result<()> {
+    fn write_vim_term_colors(&self, w: &mut dyn Write, indents: u8) -> Result<()> {
         write!(w, "{}let g:terminal_ansi_colors = [", indent(indents))?;
         for (index, name) in self.term_colors.iter().enumerate() {
             if index > 0 {
@@ -508,7 +867,7 @@ endif
         writeln!(w, "]")
     }
 
-    fn write_term_colors(&self, w: &mut impl Write) -> Result<()> {
+    fn write_term_colors(&self, w: &mut dyn Write) -> Result<()> {
         writeln!(w, "if g:spring_night_highlight_terminal")?;
         writeln!(w, "{}if has('nvim')", indent(1))?;
         self.write_nvim_term_colors(w, 2)?;
@@ -522,7 +881,72 @@ endif
         writeln!(w, "endif")
     }
 
-    pub fn write_to(&mut self, w: &mut impl Write) -> Result<()> {
+    pub fn highlights(&self) -> &'a [Highlight] {
+        self.highlights
+    }
+
+    /// Highlight of the group for GUI colors. Links are followed until the highlight defining colors
+    pub fn resolve(&self, name: &str) -> Option<&'a HiCommand> {
+        let mut name = name;
+        // Links never make a cycle. The limit is for safety
+        for _ in 0..self.highlights.len() {
+            let hl = self.highlights.iter().find(|hl| match hl {
+                Highlight::Fixed(cmd) | Highlight::Dynamic { gui: cmd, .. } => cmd.name == name,
+                Highlight::Link { name: n, .. } => *n == name,
+            })?;
+            match hl {
+                Highlight::Fixed(cmd) | Highlight::Dynamic { gui: cmd, .. } => return Some(cmd),
+                Highlight::Link { to, .. } => name = to,
+            }
+        }
+        None
+    }
+
+    pub fn term_colors(&self) -> &[&'static str; 16] {
+        &self.term_colors
+    }
+
+    /// Pairs of foreground and background colors of highlights. Unspecified colors fall back to
+    /// colors of `Normal` highlight. Highlights which specify neither of colors are omitted. Links
+    /// are omitted since their colors are checked with the linked highlights.
+    pub fn color_pairs(&self, mode: ColorMode) -> Vec<ColorPair<'static>> {
+        let cmds = self.highlights.iter().filter_map(|hl| match (hl, mode) {
+            (Highlight::Fixed(cmd), _) => Some(cmd),
+            (Highlight::Dynamic { gui, .. }, ColorMode::Gui) => Some(gui),
+            (Highlight::Dynamic { term, .. }, ColorMode::Cterm) => Some(term),
+            (Highlight::Link { .. }, _) => None,
+        });
+
+        let normal = cmds.clone().find(|cmd| cmd.name == "Normal").unwrap();
+        let (normal_fg, normal_bg) = (normal.fg.unwrap(), normal.bg.unwrap());
+
+        cmds.filter(|cmd| {
+            cmd.fg.is_some() || cmd.bg.is_some() || cmd.attr.contains(HiAttr::Reverse)
+        })
+        .map(|cmd| {
+            let fg = cmd.fg.filter(|&c| c != "NONE").unwrap_or(normal_fg);
+            let bg = cmd.bg.filter(|&c| c != "NONE").unwrap_or(normal_bg);
+            if cmd.attr.contains(HiAttr::Reverse) {
+                ColorPair::new(cmd.name, bg, fg)
+            } else {
+                ColorPair::new(cmd.name, fg, bg)
+            }
+        })
+        .collect()
+    }
+}
+
+impl ThemeWriter for Colorscheme<'_> {
+    fn target(&self) -> &'static str {
+        "vim"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&["colors", &format!("{}.vim", variant.name())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
         self.write_header(w)?;
         self.write_contrast_color_variables(w)?;
         self.write_highlights(w)?;
@@ -533,7 +957,9 @@ endif
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lua::LuaColorscheme;
     use crate::palette::Color;
+    use regex::Regex;
     use std::collections::{HashMap, HashSet};
     use std::str;
 
@@ -546,6 +972,17 @@ mod tests {
         let rendered = str::from_utf8(&out).unwrap();
         assert!(rendered.starts_with(r#"" spring-night: Calm-colored dark color scheme"#));
         assert!(rendered.contains("let g:colors_name = 'spring-night'"));
+        assert!(rendered.contains("    set background=dark\n"));
+        assert!(rendered.contains("if has('nvim-0.8')\n"));
+
+        let palette = Palette::morning();
+        let w = Colorscheme::new(&palette);
+        let mut out = vec![];
+        w.write_header(&mut out).unwrap();
+        let rendered = str::from_utf8(&out).unwrap();
+        assert!(rendered.starts_with(r#"" spring-morning: Calm-colored light color scheme"#));
+        assert!(rendered.contains("let g:colors_name = 'spring-morning'"));
+        assert!(rendered.contains("    set background=light\n"));
     }
 
     #[test]
@@ -623,6 +1060,8 @@ mod tests {
             ((None, None, Some("n"), HiAttr::Undercurl),      0, "exe 'hi' 'HL term=NONE' 'guisp=#123456' s:undercurl_attr"),
             ((None, None, None, HiAttr::Nothing),             1, "    hi HL term=NONE"),
             ((None, None, None, HiAttr::Undercurl),           1, "    exe 'hi' 'HL term=NONE' s:undercurl_attr"),
+            ((None, None, None, HiAttr::Strikethrough),       0, "hi HL term=NONE gui=strikethrough cterm=strikethrough"),
+            ((None, None, None, HiAttr::Underdashed),         0, "exe 'hi' 'HL term=NONE' s:underdashed_attr"),
         ];
 
         for ((fg, bg, sp, attr), indent, expected) in testcases {
@@ -631,7 +1070,7 @@ mod tests {
                 fg,
                 bg,
                 sp,
-                attr,
+                attr: attr.into(),
             };
             let mut m = HashMap::new();
             m.insert(
@@ -664,6 +1103,88 @@ mod tests {
         assert_eq!(str::from_utf8(&out).unwrap(), "\n");
     }
 
+    #[test]
+    fn test_resolve() {
+        let palette = Palette::default();
+        let colorscheme = Colorscheme::new(&palette);
+        for (name, resolved) in [
+            ("Normal", Some("Normal")),
+            ("@comment", Some("Comment")),
+            ("@lsp.type.enum", Some("Type")),
+            (
+                "@lsp.typemod.function.defaultLibrary",
+                Some("@function.builtin"),
+            ),
+            ("SpellBad", Some("SpellBad")),
+            ("Unknown", None),
+        ] {
+            let actual = colorscheme.resolve(name).map(|cmd| cmd.name);
+            assert_eq!(actual, resolved, "{name}");
+        }
+        // GUI highlight is chosen for dynamic highlights
+        assert_eq!(colorscheme.resolve("SpellBad").unwrap().sp, Some("red"));
+    }
+
+    #[test]
+    fn test_font_styles() {
+        for (attrs, want) in [
+            (HiAttr::Nothing.into(), &[][..]),
+            (HiAttr::Bold.into(), &["bold"][..]),
+            (HiAttr::CommentItalic.into(), &[]),
+            (HiAttr::Undercurl.into(), &["underline"]),
+            (
+                HiAttr::Italic | HiAttr::Strikethrough,
+                &["italic", "strikethrough"],
+            ),
+            (
+                HiAttr::Bold | HiAttr::Underline | HiAttr::Underdashed,
+                &["bold", "underline"],
+            ),
+        ] {
+            assert_eq!(attrs.font_styles(), want, "{attrs:?}");
+        }
+    }
+
+    #[test]
+    fn test_attr_args() {
+        #[rustfmt::skip]
+        let testcases = vec![
+            (HiAttrs::default(),                         vec![]),
+            (HiAttr::Bold.into(),                        vec!["s:bold_attr"]),
+            (HiAttr::Bold | HiAttr::Italic,              vec!["'gui='.s:bold.s:italic.'NONE'", "'cterm='.s:bold.s:cterm_italic.'NONE'"]),
+            (HiAttr::Underline | HiAttr::Reverse,        vec!["gui=underline,reverse", "cterm=underline,reverse"]),
+            (HiAttr::Bold | HiAttr::Strikethrough,       vec!["'gui='.s:bold.'strikethrough'", "'cterm='.s:bold.'strikethrough'"]),
+            (HiAttr::CommentItalic | HiAttr::Undercurl,  vec![
+                "'gui='.(g:spring_night_italic_comments ? s:italic : '').s:undercurl.'NONE'",
+                "'cterm='.(g:spring_night_italic_comments ? s:cterm_italic : '').s:undercurl.'NONE'",
+            ]),
+            (HiAttr::Bold | HiAttr::Italic | HiAttr::Underdotted | HiAttr::Nocombine, vec![
+                "'gui='.s:bold.s:italic.s:underdotted.'nocombine'",
+                "'cterm='.s:bold.s:cterm_italic.s:underdotted.'nocombine'",
+            ]),
+        ];
+
+        for (attrs, expected) in testcases {
+            assert_eq!(attr_args(attrs), expected, "{attrs:?}");
+        }
+
+        let palette = Palette::from(HashMap::new());
+        let w = Colorscheme::new(&palette);
+        let cmd = HiCommand {
+            name: "HL",
+            fg: None,
+            bg: None,
+            sp: None,
+            attr: HiAttr::Bold | HiAttr::Underline,
+        };
+        let mut out = vec![];
+        w.write_hi_command(&mut out, &cmd, 0).unwrap();
+        assert_eq!(
+            str::from_utf8(&out).unwrap(),
+            "exe 'hi' 'HL term=NONE' 'gui='.s:bold.'underline' 'cterm='.s:bold.'underline'\n",
+        );
+    }
+
     #[test]
     fn test_write_highlights() {
         const fn cmd() -> HiCommand {
@@ -672,7 +1193,7 @@ mod tests {
                 fg: None,
                 bg: None,
                 sp: None,
-                attr: HiAttr::Nothing,
+                attr: HiAttrs(0),
             }
         }
 
@@ -684,6 +1205,34 @@ mod tests {
         w.write_highlights(&mut out).unwrap();
         assert_eq!(str::from_utf8(&out).unwrap(), "hi HL term=NONE\n\n");
 
+        let capture = &[Highlight::Fixed(HiCommand {
+            name: "@variable",
+            ..cmd()
+        })];
+        let palette = Palette::from(HashMap::new());
+        let mut w = Colorscheme::new(&palette);
+        w.highlights = capture;
+        let mut out = vec![];
+        w.write_highlights(&mut out).unwrap();
+        assert_eq!(str::from_utf8(&out).unwrap(), "\n");
+
+        let links = &[
+            Highlight::Link {
+                name: "HL",
+                to: "Normal",
+            },
+            Highlight::Link {
+                name: "@variable",
+                to: "HL",
+            },
+        ];
+        let palette = Palette::from(HashMap::new());
+        let mut w = Colorscheme::new(&palette);
+        w.highlights = links;
+        let mut out = vec![];
+        w.write_highlights(&mut out).unwrap();
+        assert_eq!(str::from_utf8(&out).unwrap(), "hi! link HL Normal\n\n");
+
         let dynamic = &[Highlight::Dynamic {
             gui: cmd(),
             term: cmd(),
@@ -751,11 +1300,232 @@ mod tests {
                     assert_eq!(gui.name, term.name);
                     gui.name
                 }
+                Highlight::Link { name, .. } => name,
             };
             assert!(seen.insert(name), "Duplicate highlight '{}'", name);
         }
     }
 
+    // Check that all links point to defined highlights and no link is circular. Vim script output
+    // omits tree-sitter captures so links from other highlights must not point to them.
+    fn check_links(highlights: &[Highlight]) -> std::result::Result<(), String> {
+        let links: HashMap<_, _> = highlights
+            .iter()
+            .filter_map(|hl| match hl {
+                Highlight::Link { name, to } => Some((*name, *to)),
+                _ => None,
+            })
+            .collect();
+        let defined: HashSet<_> = highlights
+            .iter()
+            .filter_map(|hl| match hl {
+                Highlight::Fixed(cmd) => Some(cmd.name),
+                Highlight::Dynamic { gui, .. } => Some(gui.name),
+                Highlight::Link { .. } => None,
+            })
+            .collect();
+
+        for (&name, &to) in &links {
+            if !name.starts_with('@') && to.starts_with('@') {
+                return Err(format!("'{name}' links to capture '{to}'"));
+            }
+            let mut seen = vec![name];
+            let mut to = to;
+            while let Some(&next) = links.get(to) {
+                if seen.contains(&to) {
+                    return Err(format!("'{name}' has circular link {seen:?}"));
+                }
+                seen.push(to);
+                to = next;
+            }
+            if !defined.contains(to) {
+                return Err(format!("'{name}' links to unknown highlight '{to}'"));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_highlight_links() {
+        let palette = Palette::default();
+        let w = Colorscheme::new(&palette);
+        check_links(w.highlights).unwrap();
+
+        let fixed = |name| {
+            Highlight::Fixed(HiCommand {
+                name,
+                fg: None,
+                bg: None,
+                sp: None,
+                attr: HiAttrs::default(),
+            })
+        };
+        let link = |name, to| Highlight::Link { name, to };
+        for (highlights, expected) in [
+            (vec![link("B", "A")], "unknown highlight 'A'"),
+            (
+                vec![link("B", "C"), link("C", "A")],
+                "unknown highlight 'A'",
+            ),
+            (vec![link("B", "B")], "circular link"),
+            (
+                vec![link("B", "C"), link("C", "D"), link("D", "B")],
+                "circular link",
+            ),
+            (
+                vec![fixed("A"), link("B", "@a"), link("@a", "A")],
+                "links to capture '@a'",
+            ),
+        ] {
+            let err = check_links(&highlights).unwrap_err();
+            assert!(err.contains(expected), "{err:?} vs {expected:?}");
+        }
+
+        let highlights = [fixed("A"), link("B", "A"), link("C", "B"), link("@a", "C")];
+        check_links(&highlights).unwrap();
+    }
+
+    #[test]
+    fn test_lsp_links() {
+        let palette = Palette::default();
+        let w = Colorscheme::new(&palette);
+        let names: HashSet<_> = w
+            .highlights
+            .iter()
+            .map(|hl| match hl {
+                Highlight::Fixed(cmd) => cmd.name,
+                Highlight::Dynamic { gui, .. } => gui.name,
+                Highlight::Link { name, .. } => name,
+            })
+            .collect();
+
+        let mut num_links = 0;
+        for hl in w.highlights {
+            match hl {
+                Highlight::Link { name, to } if name.starts_with("@lsp.") => {
+                    assert!(!to.starts_with("@lsp."), "{name} -> {to}");
+                    assert!(names.contains(to), "{name} -> {to}");
+                    num_links += 1;
+                }
+                // Only modifiers without token types can have their own colors
+                Highlight::Fixed(cmd) if cmd.name.starts_with("@lsp.") => {
+                    assert!(cmd.name.starts_with("@lsp.mod."), "{}", cmd.name);
+                }
+                _ => {}
+            }
+        }
+        assert!(num_links > 0);
+    }
+
+    #[test]
+    fn test_capture_links_in_outputs() {
+        fn defined_and_links<'a>(
+            src: &'a str,
+            def: &str,
+            link: &str,
+        ) -> (HashSet<&'a str>, Vec<(&'a str, &'a str)>) {
+            let def = Regex::new(def).unwrap();
+            let link = Regex::new(link).unwrap();
+            let defined = def
+                .captures_iter(src)
+                .map(|c| c.get(1).unwrap().as_str())
+                .collect();
+            let links = link
+                .captures_iter(src)
+                .map(|c| (c.get(1).unwrap().as_str(), c.get(2).unwrap().as_str()))
+                .collect();
+            (defined, links)
+        }
+
+        for palette in [Palette::default(), Palette::morning()] {
+            let w = Colorscheme::new(&palette);
+            let captures: Vec<_> = w
+                .highlights
+                .iter()
+                .filter_map(|hl| match hl {
+                    Highlight::Fixed(cmd) => Some((cmd.name, None)),
+                    Highlight::Link { name, to } => Some((*name, Some(*to))),
+                    Highlight::Dynamic { .. } => None,
+                })
+                .filter(|(name, _)| name.starts_with('@'))
+                .collect();
+            assert!(!captures.is_empty());
+
+            let mut out = vec![];
+            w.write_to(&mut out).unwrap();
+            let vim = str::from_utf8(&out).unwrap();
+            let (vim_defined, vim_links) = defined_and_links(
+                vim,
+                r"(?m)^\s*(?:hi |exe 'hi' '|hi! link )([^\s']+)",
+                r"(?m)^hi! link (\S+) (\S+)$",
+            );
+
+            let mut out = vec![];
+            LuaColorscheme::new(&palette).write_to(&mut out).unwrap();
+            let lua = str::from_utf8(&out).unwrap();
+            let (lua_defined, lua_links) = defined_and_links(
+                lua,
+                r"(?m)^\s*hl\(0, '([^']+)'",
+                r"(?m)^hl\(0, '([^']+)', \{ link = '([^']+)' \}\)$",
+            );
+
+            for (name, to) in vim_links {
+                assert!(vim_defined.contains(to), "Vim: {name} -> {to}");
+            }
+            for (name, to) in lua_links {
+                assert!(lua_defined.contains(to), "Lua: {name} -> {to}");
+            }
+            for (name, to) in captures {
+                assert!(lua_defined.contains(name), "Lua: {name}");
+                assert!(!vim_defined.contains(name), "Vim: {name}");
+                // Captures linking to Vim groups get the same colors as the groups in Vim
+                if let Some(to) = to.filter(|to| !to.starts_with('@')) {
+                    assert!(vim_defined.contains(to), "Vim: {name} -> {to}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_pairs() {
+        let palette = Palette::default();
+        let mut w = Colorscheme::new(&palette);
+        let cmd = |name, fg, bg, attr: HiAttr| HiCommand {
+            name,
+            fg,
+            bg,
+            sp: None,
+            attr: attr.into(),
+        };
+        let highlights = &[
+            Highlight::Fixed(cmd("Normal", Some("fg"), Some("bg"), HiAttr::Nothing)),
+            Highlight::Fixed(cmd("Fg", Some("red"), None, HiAttr::Nothing)),
+            Highlight::Fixed(cmd("Bg", None, Some("sakura"), HiAttr::Nothing)),
+            Highlight::Fixed(cmd("None", Some("NONE"), Some("nasu"), HiAttr::Underline)),
+            Highlight::Fixed(cmd("Empty", None, None, HiAttr::Bold)),
+            Highlight::Dynamic {
+                gui: cmd("Dyn", Some("gold"), None, HiAttr::Nothing),
+                term: cmd("Dyn", None, None, HiAttr::Reverse),
+            },
+        ];
+        w.highlights = highlights;
+
+        assert_eq!(
+            w.color_pairs(ColorMode::Gui),
+            vec![
+                ColorPair::new("Normal", "fg", "bg"),
+                ColorPair::new("Fg", "red", "bg"),
+                ColorPair::new("Bg", "fg", "sakura"),
+                ColorPair::new("None", "fg", "nasu"),
+                ColorPair::new("Dyn", "gold", "bg"),
+            ],
+        );
+        assert_eq!(
+            w.color_pairs(ColorMode::Cterm).last(),
+            Some(&ColorPair::new("Dyn", "bg", "fg")),
+        );
+    }
+
     #[test]
     fn test_term_colors() {
         let palette = Palette::default();
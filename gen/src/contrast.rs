@@ -0,0 +1,264 @@
+use crate::airline::AirlineTheme;
+use crate::alacritty::AlacrittyTheme;
+use crate::color::Rgb;
+use crate::colorscheme::Colorscheme;
+use crate::foot::FootTheme;
+use crate::kitty::KittyTheme;
+use crate::lightline::LightlineTheme;
+use crate::palette::{ColorCode, Palette};
+use crate::wezterm::WezTermTheme;
+use std::fmt;
+use std::io::{Result, Write};
+
+// Pair of foreground and background color names which are rendered together
+#[derive(Debug, PartialEq)]
+pub struct ColorPair<'a> {
+    pub label: String,
+    pub fg: &'a str,
+    pub bg: &'a str,
+}
+
+impl<'a> ColorPair<'a> {
+    pub fn new(label: impl Into<String>, fg: &'a str, bg: &'a str) -> Self {
+        Self {
+            label: label.into(),
+            fg,
+            bg,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorMode {
+    Gui,
+    Cterm,
+}
+
+impl fmt::Display for ColorMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gui => write!(f, "gui"),
+            Self::Cterm => write!(f, "cterm"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LowContrast {
+    pub target: &'static str,
+    pub mode: ColorMode,
+    pub high_contrast: bool,
+    pub label: String,
+    pub fg: Rgb,
+    pub bg: Rgb,
+    pub ratio: f64,
+    pub apca: f64,
+}
+
+#[derive(Debug)]
+pub struct ContrastChecker<'a> {
+    palette: &'a Palette<'a>,
+    threshold: f64,
+}
+
+impl<'a> ContrastChecker<'a> {
+    pub fn new(palette: &'a Palette<'a>, threshold: f64) -> Self {
+        Self { palette, threshold }
+    }
+
+    fn rgb(&self, name: &str, mode: ColorMode, high_contrast: bool) -> Rgb {
+        fn select<T: fmt::Display + Copy>(code: &ColorCode<T>, high_contrast: bool) -> T {
+            match code {
+                ColorCode::Normal(c) => *c,
+                ColorCode::Contrast(h, l) => {
+                    if high_contrast {
+                        *h
+                    } else {
+                        *l
+                    }
+                }
+            }
+        }
+
+        let color = &self.palette[name];
+        match mode {
+            ColorMode::Gui => Rgb::from_hex(select(&color.gui, high_contrast)).unwrap(),
+            ColorMode::Cterm => Rgb::from_xterm(select(&color.cterm, high_contrast)),
+        }
+    }
+
+    fn has_contrast(&self, pair: &ColorPair<'_>, mode: ColorMode) -> bool {
+        [pair.fg, pair.bg].into_iter().any(|name| {
+            let color = &self.palette[name];
+            match mode {
+                ColorMode::Gui => matches!(color.gui, ColorCode::Contrast(..)),
+                ColorMode::Cterm => matches!(color.cterm, ColorCode::Contrast(..)),
+            }
+        })
+    }
+
+    fn check_pairs(
+        &self,
+        target: &'static str,
+        mode: ColorMode,
+        pairs: &[ColorPair<'_>],
+        issues: &mut Vec<LowContrast>,
+    ) {
+        for high_contrast in [true, false] {
+            for pair in pairs {
+                // Pairs without high and low contrast colors are the same in both passes
+                if !high_contrast && !self.has_contrast(pair, mode) {
+                    continue;
+                }
+                let fg = self.rgb(pair.fg, mode, high_contrast);
+                let bg = self.rgb(pair.bg, mode, high_contrast);
+                let ratio = fg.contrast_ratio(bg);
+                if ratio < self.threshold {
+                    issues.push(LowContrast {
+                        target,
+                        mode,
+                        high_contrast,
+                        label: pair.label.clone(),
+                        fg,
+                        bg,
+                        ratio,
+                        apca: fg.apca_contrast(bg),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Check contrast ratios of all highlights and themes with both high and low contrast colors.
+    pub fn check(&self) -> Vec<LowContrast> {
+        let mut issues = vec![];
+
+        let colorscheme = Colorscheme::new(self.palette);
+        for mode in [ColorMode::Gui, ColorMode::Cterm] {
+            let pairs = colorscheme.color_pairs(mode);
+            self.check_pairs("colorscheme", mode, &pairs, &mut issues);
+        }
+
+        let pairs = AirlineTheme::new(self.palette).color_pairs();
+        for mode in [ColorMode::Gui, ColorMode::Cterm] {
+            self.check_pairs("airline", mode, &pairs, &mut issues);
+        }
+
+        let pairs = LightlineTheme::new(self.palette).color_pairs();
+        for mode in [ColorMode::Gui, ColorMode::Cterm] {
+            self.check_pairs("lightline", mode, &pairs, &mut issues);
+        }
+
+        // Alacritty supports only 24-bit colors
+        let pairs = AlacrittyTheme::new(self.palette).color_pairs();
+        self.check_pairs("alacritty", ColorMode::Gui, &pairs, &mut issues);
+
+        let pairs = KittyTheme::new(self.palette).color_pairs();
+        self.check_pairs("kitty", ColorMode::Gui, &pairs, &mut issues);
+
+        let pairs = WezTermTheme::new(self.palette).color_pairs();
+        self.check_pairs("wezterm", ColorMode::Gui, &pairs, &mut issues);
+
+        let pairs = FootTheme::new(self.palette).color_pairs();
+        self.check_pairs("foot", ColorMode::Gui, &pairs, &mut issues);
+
+        issues
+    }
+
+    pub fn write_report(&self, w: &mut impl Write) -> Result<usize> {
+        let issues = self.check();
+        let variant = self.palette.variant().name();
+        for issue in &issues {
+            writeln!(
+                w,
+                "{variant}: {target} ({mode}, {contrast} contrast): {label}: {fg} on {bg}: contrast ratio {ratio:.2} (APCA Lc {apca:.1})",
+                target = issue.target,
+                mode = issue.mode,
+                contrast = if issue.high_contrast { "high" } else { "low" },
+                label = issue.label,
+                fg = issue.fg.hex(),
+                bg = issue.bg.hex(),
+                ratio = issue.ratio,
+                apca = issue.apca,
+            )?;
+        }
+        Ok(issues.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::palette::Color;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_check_pairs() {
+        let mut m = HashMap::new();
+        m.insert(
+            "black",
+            Color {
+                gui: ColorCode::Normal("#000000"),
+                cterm: ColorCode::Normal(16),
+            },
+        );
+        m.insert(
+            "gray",
+            Color {
+                gui: ColorCode::Contrast("#ffffff", "#222222"),
+                cterm: ColorCode::Normal(235),
+            },
+        );
+        let palette = Palette::from(m);
+        let checker = ContrastChecker::new(&palette, 4.5);
+        let pairs = [ColorPair::new("Pair", "gray", "black")];
+
+        let mut issues = vec![];
+        checker.check_pairs("test", ColorMode::Gui, &pairs, &mut issues);
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        let issue = &issues[0];
+        assert_eq!(issue.label, "Pair");
+        assert!(!issue.high_contrast);
+        assert_eq!(issue.fg.hex(), "#222222");
+        assert_eq!(issue.bg.hex(), "#000000");
+        assert!(issue.ratio < 4.5, "{issue:?}");
+        assert!(issue.apca <= 0.0, "{issue:?}");
+
+        // cterm colors have no high and low contrast variants so the pair is checked only once
+        let mut issues = vec![];
+        checker.check_pairs("test", ColorMode::Cterm, &pairs, &mut issues);
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert!(issues[0].high_contrast, "{issues:?}");
+        assert_eq!(issues[0].fg.hex(), "#262626");
+    }
+
+    #[test]
+    fn test_check_default_palettes() {
+        for palette in [Palette::default(), Palette::morning()] {
+            let checker = ContrastChecker::new(&palette, 1.5);
+            let mut out = vec![];
+            let count = checker.write_report(&mut out).unwrap();
+            let out = String::from_utf8(out).unwrap();
+            assert_eq!(out.lines().count(), count, "{out}");
+            assert!(
+                out.lines().all(|l| l.starts_with(palette.variant().name())),
+                "{out}",
+            );
+
+            let checker = ContrastChecker::new(&palette, 21.1);
+            let issues = checker.check();
+            for target in ["colorscheme", "airline", "lightline", "alacritty"] {
+                assert!(issues.iter().any(|i| i.target == target), "{target}");
+            }
+            for label in [
+                "Normal",
+                "EndOfBuffer",
+                "SpecialKey",
+                "normal a",
+                "selection",
+            ] {
+                assert!(issues.iter().any(|i| i.label == label), "{label}");
+            }
+        }
+    }
+}
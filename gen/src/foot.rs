@@ -0,0 +1,198 @@
+use crate::alacritty::AlacrittyTheme;
+use crate::colorscheme::Colorscheme;
+use crate::contrast::ColorPair;
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+type Color<'a> = (&'a str, &'a str); // Pair of foreground/background colors
+
+// foot theme. ANSI colors, selection colors, jump labels and search box colors on match are shared
+// with `AlacrittyTheme`. URLs and search box colors on no match follow Underlined and ErrorMsg
+// highlights of `Colorscheme`
+#[derive(Debug)]
+pub struct FootTheme<'a> {
+    palette: &'a Palette<'a>,
+    primary: Color<'a>,
+    regular: [&'a str; 8],
+    bright: [&'a str; 8],
+    dim: [&'a str; 8],
+    selection: Color<'a>,
+    urls: &'a str,
+    jump_labels: Color<'a>,
+    search_box_match: Color<'a>,
+    search_box_no_match: Color<'a>,
+}
+
+impl<'a> FootTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let alacritty = AlacrittyTheme::new(palette);
+        let colorscheme = Colorscheme::new(palette);
+        let hl = |name| colorscheme.resolve(name).unwrap();
+        Self {
+            palette,
+            primary: (alacritty.foreground(), alacritty.background()),
+            regular: alacritty.normal().colors(),
+            bright: alacritty.bright().colors(),
+            dim: alacritty.dim().colors(),
+            selection: alacritty.selection(),
+            urls: hl("Underlined").fg.unwrap(),
+            jump_labels: alacritty.hint(),
+            search_box_match: alacritty.search(),
+            // Vim shows "Pattern not found" error with ErrorMsg highlight
+            search_box_no_match: (hl("ErrorMsg").fg.unwrap(), hl("ErrorMsg").bg.unwrap()),
+        }
+    }
+
+    // foot requires colors in RRGGBB format without '#'
+    fn color(&self, name: &str) -> &'_ str {
+        self.palette[name].gui.normal().trim_start_matches('#')
+    }
+
+    fn write_header(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(
+            w,
+            r#"# foot theme for {name} colorscheme
+#
+# Author: rhysd <lin90162@yahoo.co.jp>
+# License: MIT
+#   Copyright (c) 2016 rhysd
+#
+# PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+# Generated by script vim-color-spring-night/gen/{source}
+"#,
+            name = self.palette.variant().name(),
+            source = file!(),
+        )
+    }
+
+    fn write_color(&self, w: &mut dyn Write, key: &str, name: &str) -> Result<()> {
+        writeln!(w, "{key}={}", self.color(name))
+    }
+
+    fn write_color_pair(&self, w: &mut dyn Write, key: &str, (fg, bg): Color<'_>) -> Result<()> {
+        writeln!(w, "{key}={} {}", self.color(fg), self.color(bg))
+    }
+
+    /// Pairs of foreground and background colors rendered by foot
+    pub fn color_pairs(&self) -> Vec<ColorPair<'a>> {
+        let mut pairs = vec![];
+        for (label, (fg, bg)) in [
+            ("primary", self.primary),
+            ("selection", self.selection),
+            ("urls", (self.urls, self.primary.1)),
+            ("jump-labels", self.jump_labels),
+            ("search-box-match", self.search_box_match),
+            ("search-box-no-match", self.search_box_no_match),
+        ] {
+            pairs.push(ColorPair::new(label, fg, bg));
+        }
+        pairs
+    }
+}
+
+impl ThemeWriter for FootTheme<'_> {
+    fn target(&self) -> &'static str {
+        "foot"
+    }
+
+    fn path(&self) -> PathBuf {
+        theme::path(&["foot", &format!("{}.ini", self.palette.variant().name())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        let (fg, bg) = self.primary;
+        let (selection_fg, selection_bg) = self.selection;
+
+        self.write_header(w)?;
+        writeln!(w, "[colors]")?;
+        self.write_color(w, "foreground", fg)?;
+        self.write_color(w, "background", bg)?;
+        for (prefix, colors) in [
+            ("regular", &self.regular),
+            ("bright", &self.bright),
+            ("dim", &self.dim),
+        ] {
+            for (i, name) in colors.iter().enumerate() {
+                self.write_color(w, &format!("{prefix}{i}"), name)?;
+            }
+        }
+        self.write_color(w, "selection-foreground", selection_fg)?;
+        self.write_color(w, "selection-background", selection_bg)?;
+        self.write_color(w, "urls", self.urls)?;
+        self.write_color_pair(w, "jump-labels", self.jump_labels)?;
+        self.write_color_pair(w, "search-box-no-match", self.search_box_no_match)?;
+        self.write_color_pair(w, "search-box-match", self.search_box_match)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use std::collections::HashMap;
+    use std::str;
+
+    #[test]
+    fn test_write_theme() {
+        let re_option =
+            Regex::new(r"^([a-z0-9-]+)=([[:xdigit:]]{6}(?: [[:xdigit:]]{6})?)$").unwrap();
+
+        for palette in [Palette::default(), Palette::morning()] {
+            let w = FootTheme::new(&palette);
+            let mut out = vec![];
+            w.write_to(&mut out).unwrap();
+            let rendered = str::from_utf8(&out).unwrap();
+
+            let mut lines = rendered
+                .lines()
+                .filter(|l| !l.is_empty() && !l.starts_with('#'));
+            assert_eq!(lines.next(), Some("[colors]"));
+
+            let mut options = HashMap::new();
+            for line in lines {
+                let found = re_option.captures(line).expect(line);
+                let (key, value) = (found[1].to_string(), found[2].to_string());
+                assert!(options.insert(key, value).is_none(), "{line}");
+            }
+
+            let mut keys = vec![];
+            for prefix in ["regular", "bright", "dim"] {
+                keys.extend((0..8).map(|i| format!("{prefix}{i}")));
+            }
+            for key in [
+                "foreground",
+                "background",
+                "selection-foreground",
+                "selection-background",
+                "urls",
+            ] {
+                keys.push(key.to_string());
+            }
+            for key in &keys {
+                let color = options.get(key).expect(key);
+                assert_eq!(color.len(), 6, "{key}: {color}");
+            }
+            for key in ["jump-labels", "search-box-no-match", "search-box-match"] {
+                let colors = options.get(key).expect(key);
+                assert_eq!(colors.len(), 13, "{key}: {colors}");
+            }
+            assert_eq!(options.len(), keys.len() + 3, "{options:?}");
+
+            let color = |name: &str| palette[name].gui.normal().trim_start_matches('#');
+            let colorscheme = Colorscheme::new(&palette);
+            let underlined = colorscheme.resolve("Underlined").unwrap();
+            assert_eq!(options["urls"], color(underlined.fg.unwrap()));
+            let error = colorscheme.resolve("ErrorMsg").unwrap();
+            assert_eq!(
+                options["search-box-no-match"],
+                format!("{} {}", color(error.fg.unwrap()), color(error.bg.unwrap())),
+            );
+            assert_eq!(
+                options["regular1"],
+                palette["crimson"].gui.normal().trim_start_matches('#'),
+            );
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::alacritty::AlacrittyTheme;
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+// Ghostty theme. Colors are shared with `AlacrittyTheme`
+#[derive(Debug)]
+pub struct GhosttyTheme<'a> {
+    palette: &'a Palette<'a>,
+    ansi: [&'a str; 16],
+    colors: Vec<(&'static str, &'a str)>,
+}
+
+impl<'a> GhosttyTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let alacritty = AlacrittyTheme::new(palette);
+        let (selection_fg, selection_bg) = alacritty.selection();
+        Self {
+            palette,
+            ansi: alacritty.ansi_colors(),
+            colors: vec![
+                ("background", alacritty.background()),
+                ("foreground", alacritty.foreground()),
+                ("cursor-color", alacritty.foreground()),
+                ("selection-background", selection_bg),
+                ("selection-foreground", selection_fg),
+            ],
+        }
+    }
+
+    fn color(&self, name: &str) -> &'_ str {
+        self.palette[name].gui.normal()
+    }
+}
+
+impl ThemeWriter for GhosttyTheme<'_> {
+    fn target(&self) -> &'static str {
+        "ghostty"
+    }
+
+    fn path(&self) -> PathBuf {
+        // Ghostty finds a theme by its file name
+        theme::path(&["ghostty", self.palette.variant().name()])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(
+            w,
+            r#"# Ghostty theme for {name} colorscheme
+#
+# Author: rhysd <lin90162@yahoo.co.jp>
+# License: MIT
+#   Copyright (c) 2016 rhysd
+#
+# PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+# Generated by script vim-color-spring-night/gen/{source}
+"#,
+            name = self.palette.variant().name(),
+            source = file!(),
+        )?;
+        for (i, name) in self.ansi.iter().enumerate() {
+            writeln!(w, "palette = {i}={}", self.color(name))?;
+        }
+        for (key, name) in &self.colors {
+            writeln!(w, "{key} = {}", self.color(name))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::tests::assert_palette_colors;
+    use regex::Regex;
+    use std::collections::HashMap;
+    use std::str;
+
+    #[test]
+    fn test_write_theme() {
+        let re_line = Regex::new(r"^([a-z-]+) = (?:(\d+)=)?(#\w+)$").unwrap();
+
+        for palette in [Palette::default(), Palette::morning()] {
+            let w = GhosttyTheme::new(&palette);
+            let mut out = vec![];
+            w.write_to(&mut out).unwrap();
+            let rendered = str::from_utf8(&out).unwrap();
+            assert_palette_colors(&palette, rendered);
+
+            let mut ansi = vec![];
+            let mut options = HashMap::new();
+            for line in rendered.lines() {
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                let found = re_line.captures(line).expect(line);
+                let color = found[3].to_string();
+                if &found[1] == "palette" {
+                    let idx: usize = found[2].parse().unwrap();
+                    assert_eq!(idx, ansi.len(), "{line}");
+                    ansi.push(color);
+                } else {
+                    assert!(found.get(2).is_none(), "{line}");
+                    assert!(
+                        options.insert(found[1].to_string(), color).is_none(),
+                        "{line}"
+                    );
+                }
+            }
+
+            let expected: Vec<_> = AlacrittyTheme::new(&palette)
+                .ansi_colors()
+                .iter()
+                .map(|name| palette[name].gui.normal().to_string())
+                .collect();
+            assert_eq!(ansi, expected);
+
+            for key in [
+                "background",
+                "foreground",
+                "cursor-color",
+                "selection-background",
+                "selection-foreground",
+            ] {
+                assert!(options.contains_key(key), "{key} is missing: {options:?}");
+            }
+            assert_eq!(options.len(), 5, "{options:?}");
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::colorscheme::Highlight;
+
+// Highlight groups defined by Vim. See `:help highlight-groups`, `:help group-name` and
+// `:help termdebug`.
+#[rustfmt::skip]
+const VIM_GROUPS: &[&str] = &[
+    // UI
+    "ColorColumn", "Conceal", "CurSearch", "Cursor", "lCursor", "CursorIM", "CursorColumn",
+    "CursorLine", "Directory", "DiffAdd", "DiffChange", "DiffDelete", "DiffText", "EndOfBuffer",
+    "ErrorMsg", "VertSplit", "Folded", "FoldColumn", "SignColumn", "IncSearch", "LineNr",
+    "LineNrAbove", "LineNrBelow", "CursorLineNr", "CursorLineFold", "CursorLineSign", "MatchParen",
+    "MessageWindow", "ModeMsg", "MoreMsg", "NonText", "Normal", "Pmenu", "PmenuSel", "PmenuKind",
+    "PmenuKindSel", "PmenuExtra", "PmenuExtraSel", "PmenuSbar", "PmenuThumb", "PmenuMatch",
+    "PmenuMatchSel", "PopupNotification", "PopupSelected", "Question", "QuickFixLine", "Search",
+    "SpecialKey", "SpellBad", "SpellCap", "SpellLocal", "SpellRare", "StatusLine", "StatusLineNC",
+    "StatusLineTerm", "StatusLineTermNC", "TabLine", "TabLineFill", "TabLineSel", "Terminal",
+    "Title", "Visual", "VisualNOS", "WarningMsg", "WildMenu", "ToolbarLine", "ToolbarButton",
+    "Menu", "Scrollbar", "Tooltip", "User1", "User2", "User3", "User4", "User5", "User6", "User7",
+    "User8", "User9",
+    // Syntax
+    "Comment", "Constant", "String", "Character", "Number", "Boolean", "Float", "Identifier",
+    "Function", "Statement", "Conditional", "Repeat", "Label", "Operator", "Keyword", "Exception",
+    "PreProc", "Include", "Define", "Macro", "PreCondit", "Type", "StorageClass", "Structure",
+    "Typedef", "Special", "SpecialChar", "Tag", "Delimiter", "SpecialComment", "Debug",
+    "Underlined", "Ignore", "Error", "Todo", "Added", "Changed", "Removed",
+    // Termdebug
+    "debugPC", "debugBreakpoint", "debugBreakpointDisabled",
+];
+
+// Highlight groups defined only by Neovim. See `:help highlight-groups`, `:help diagnostic-highlights`
+// and `:help lsp-highlight`.
+#[rustfmt::skip]
+const NVIM_GROUPS: &[&str] = &[
+    // UI
+    "NormalFloat", "NormalNC", "FloatBorder", "FloatTitle", "FloatFooter", "MsgArea",
+    "MsgSeparator", "Substitute", "TermCursor", "TermCursorNC", "Whitespace", "WinBar", "WinBarNC",
+    "WinSeparator", "NvimInternalError",
+    // Diagnostics
+    "DiagnosticError", "DiagnosticWarn", "DiagnosticInfo", "DiagnosticHint", "DiagnosticOk",
+    "DiagnosticVirtualTextError", "DiagnosticVirtualTextWarn", "DiagnosticVirtualTextInfo",
+    "DiagnosticVirtualTextHint", "DiagnosticVirtualTextOk", "DiagnosticVirtualLinesError",
+    "DiagnosticVirtualLinesWarn", "DiagnosticVirtualLinesInfo", "DiagnosticVirtualLinesHint",
+    "DiagnosticVirtualLinesOk", "DiagnosticUnderlineError", "DiagnosticUnderlineWarn",
+    "DiagnosticUnderlineInfo", "DiagnosticUnderlineHint", "DiagnosticUnderlineOk",
+    "DiagnosticFloatingError", "DiagnosticFloatingWarn", "DiagnosticFloatingInfo",
+    "DiagnosticFloatingHint", "DiagnosticFloatingOk", "DiagnosticSignError", "DiagnosticSignWarn",
+    "DiagnosticSignInfo", "DiagnosticSignHint", "DiagnosticSignOk", "DiagnosticDeprecated",
+    "DiagnosticUnnecessary",
+    // LSP
+    "LspReferenceText", "LspReferenceRead", "LspReferenceWrite", "LspReferenceTarget",
+    "LspInlayHint", "LspCodeLens", "LspCodeLensSeparator", "LspSignatureActiveParameter",
+];
+
+// Prefixes of highlight groups defined by syntax files and plugins. A group belongs to the namespace
+// when its name is the prefix or starts with the prefix followed by an upper case letter.
+#[rustfmt::skip]
+const NAMESPACES: &[&str] = &[
+    // Filetypes
+    "c", "cmake", "diff", "gitcommit", "gitconfig", "glsl", "go", "help", "html", "javaScript",
+    "make", "man", "markdown", "ocaml", "plantuml", "python", "qf", "rst", "ruby", "rust", "scala",
+    "tmux", "toml", "typescript", "vim", "wat", "zig", "zsh",
+    // Plugins
+    "ALE", "CleverF", "ConflictMarker", "Dirvish", "EasyMotion", "Flake8_", "GitGutter",
+    "Highlightedyank", "IndentGuides", "Signify", "plug", "vimfiler",
+];
+
+// Top-level names of Neovim's tree-sitter captures. See `:help treesitter-highlight-groups`.
+#[rustfmt::skip]
+const CAPTURES: &[&str] = &[
+    "variable", "constant", "module", "label", "string", "character", "boolean", "number", "type",
+    "attribute", "property", "function", "constructor", "operator", "keyword", "punctuation",
+    "comment", "markup", "diff", "tag",
+];
+
+// Kinds of Neovim's LSP semantic token highlights. See `:help lsp-semantic-highlight`.
+const LSP_KINDS: &[&str] = &["type", "mod", "typemod"];
+
+fn in_namespace(name: &str) -> bool {
+    NAMESPACES.iter().any(|ns| match name.strip_prefix(ns) {
+        Some(rest) => rest.is_empty() || rest.starts_with(|c: char| c.is_ascii_uppercase()),
+        None => false,
+    })
+}
+
+fn is_capture(name: &str) -> bool {
+    let Some(name) = name.strip_prefix('@') else {
+        return false;
+    };
+    let mut parts = name.split('.');
+    match parts.next() {
+        Some("lsp") => parts.next().is_some_and(|k| LSP_KINDS.contains(&k)),
+        Some(top) => CAPTURES.contains(&top),
+        None => false,
+    }
+}
+
+pub fn is_known_group(name: &str) -> bool {
+    VIM_GROUPS.contains(&name)
+        || NVIM_GROUPS.contains(&name)
+        || in_namespace(name)
+        || is_capture(name)
+}
+
+/// Names of highlights which are neither builtin groups, tree-sitter captures nor groups in known
+/// namespaces. Such highlights are likely typos and have no effect.
+pub fn unknown_groups(highlights: &[Highlight]) -> Vec<&'static str> {
+    highlights
+        .iter()
+        .map(|hl| match hl {
+            Highlight::Fixed(cmd) => cmd.name,
+            Highlight::Dynamic { gui, .. } => gui.name,
+            Highlight::Link { name, .. } => name,
+        })
+        .filter(|name| !is_known_group(name))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colorscheme::Colorscheme;
+    use crate::palette::Palette;
+
+    #[test]
+    fn test_is_known_group() {
+        for name in [
+            "Normal",
+            "Operator",
+            "NormalFloat",
+            "DiagnosticSignError",
+            "debugBreakpoint",
+            "cStorageClass",
+            "cmakeArguments",
+            "gitcommitSummary",
+            "javaScriptBraces",
+            "vimfilerColumn__SizeLine",
+            "ALEError",
+            "Flake8_Error",
+            "ConflictMarker",
+            "@variable",
+            "@keyword.return",
+            "@lsp.type.class",
+            "@lsp.typemod.function.defaultLibrary",
+        ] {
+            assert!(is_known_group(name), "{name}");
+        }
+
+        for name in [
+            "Operater",
+            "Normall",
+            "debugBreakPoint",
+            "gitCommitSummary",
+            "cstorageClass",
+            "ALEerror",
+            "@",
+            "@variables",
+            "@lsp",
+            "@lsp.types.class",
+        ] {
+            assert!(!is_known_group(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_no_unknown_groups() {
+        let palette = Palette::default();
+        let colorscheme = Colorscheme::new(&palette);
+        let unknown = unknown_groups(colorscheme.highlights());
+        assert!(unknown.is_empty(), "{unknown:?}");
+    }
+}
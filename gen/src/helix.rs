@@ -0,0 +1,337 @@
+use crate::airline::AirlineTheme;
+use crate::colorscheme::{Colorscheme, HiAttr, HiCommand};
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+// Helix scopes and the highlight groups giving their styles
+#[rustfmt::skip]
+const SCOPES: &[(&str, &str)] = &[
+    ("ui.cursor",                       "Cursor"),
+    ("ui.cursor.match",                 "MatchParen"),
+    ("ui.cursorline.primary",           "CursorLine"),
+    ("ui.selection",                    "Visual"),
+    ("ui.linenr",                       "LineNr"),
+    ("ui.linenr.selected",              "CursorLineNr"),
+    ("ui.gutter",                       "SignColumn"),
+    ("ui.window",                       "VertSplit"),
+    ("ui.popup",                        "NormalFloat"),
+    ("ui.help",                         "NormalFloat"),
+    ("ui.menu",                         "Pmenu"),
+    ("ui.menu.selected",                "PmenuSel"),
+    ("ui.bufferline",                   "TabLine"),
+    ("ui.bufferline.active",            "TabLineSel"),
+    ("ui.virtual.whitespace",           "SpecialKey"),
+    ("ui.virtual.ruler",                "ColorColumn"),
+    ("ui.text.focus",                   "PmenuSel"),
+    ("error",                           "DiagnosticError"),
+    ("warning",                         "DiagnosticWarn"),
+    ("info",                            "DiagnosticInfo"),
+    ("hint",                            "DiagnosticHint"),
+    ("diagnostic.error",                "DiagnosticUnderlineError"),
+    ("diagnostic.warning",              "DiagnosticUnderlineWarn"),
+    ("diagnostic.info",                 "DiagnosticUnderlineInfo"),
+    ("diagnostic.hint",                 "DiagnosticUnderlineHint"),
+    ("diagnostic.deprecated",           "@lsp.mod.deprecated"),
+    ("diff.plus",                       "diffAdded"),
+    ("diff.minus",                      "diffRemoved"),
+    ("diff.delta",                      "SignifySignChange"),
+    ("markup.heading",                  "@markup.heading"),
+    ("markup.bold",                     "@markup.strong"),
+    ("markup.italic",                   "@markup.italic"),
+    ("markup.strikethrough",            "@markup.strikethrough"),
+    ("markup.list",                     "@markup.list"),
+    ("markup.link.url",                 "@markup.link.url"),
+    ("markup.link.text",                "@markup.link"),
+    ("markup.quote",                    "@markup.quote"),
+    ("markup.raw",                      "@markup.raw"),
+    ("attribute",                       "@attribute"),
+    ("type",                            "Type"),
+    ("type.builtin",                    "@type.builtin"),
+    ("constructor",                     "@constructor"),
+    ("constant",                        "Constant"),
+    ("constant.builtin",                "@constant.builtin"),
+    ("constant.builtin.boolean",        "Boolean"),
+    ("constant.character",              "Character"),
+    ("constant.character.escape",       "@string.escape"),
+    ("constant.numeric",                "Number"),
+    ("string",                          "String"),
+    ("string.regexp",                   "@string.regexp"),
+    ("comment",                         "Comment"),
+    ("comment.block.documentation",     "@comment.documentation"),
+    ("variable",                        "@variable"),
+    ("variable.builtin",                "@variable.builtin"),
+    ("variable.parameter",              "@variable.parameter"),
+    ("variable.other.member",           "@property"),
+    ("label",                           "Label"),
+    ("punctuation",                     "@punctuation.delimiter"),
+    ("punctuation.special",             "@punctuation.special"),
+    ("keyword",                         "Keyword"),
+    ("keyword.control",                 "Statement"),
+    ("keyword.control.conditional",     "Conditional"),
+    ("keyword.control.repeat",          "@keyword.repeat"),
+    ("keyword.control.import",          "@keyword.import"),
+    ("keyword.operator",                "@keyword.operator"),
+    ("keyword.directive",               "PreProc"),
+    ("keyword.storage",                 "StorageClass"),
+    ("keyword.storage.type",            "@keyword.type"),
+    ("operator",                        "Operator"),
+    ("function",                        "Function"),
+    ("function.builtin",                "@function.builtin"),
+    ("function.method",                 "@function.method"),
+    ("function.macro",                  "@function.macro"),
+    ("tag",                             "Tag"),
+    ("namespace",                       "@module"),
+    ("special",                         "Special"),
+];
+
+// Style of Helix scope. Colors are names in [palette] table
+#[derive(Debug, Default)]
+struct Style<'a> {
+    fg: Option<&'a str>,
+    bg: Option<&'a str>,
+    underline: Option<(Option<&'a str>, &'static str)>, // Pair of color and style
+    modifiers: Vec<&'static str>,
+}
+
+impl<'a> Style<'a> {
+    fn colors((fg, bg): (&'a str, &'a str)) -> Self {
+        Self {
+            fg: Some(fg),
+            bg: Some(bg),
+            ..Default::default()
+        }
+    }
+
+    fn bold(mut self) -> Self {
+        self.modifiers.push("bold");
+        self
+    }
+}
+
+impl From<&HiCommand> for Style<'static> {
+    fn from(cmd: &HiCommand) -> Self {
+        let color = |c: Option<&'static str>| c.filter(|&c| c != "NONE");
+        let attr = cmd.attr;
+        let underline = [
+            (HiAttr::Underline, "line"),
+            (HiAttr::Undercurl, "curl"),
+            (HiAttr::Underdouble, "double_line"),
+            (HiAttr::Underdotted, "dotted"),
+            (HiAttr::Underdashed, "dashed"),
+        ]
+        .into_iter()
+        .find(|&(a, _)| attr.contains(a))
+        .map(|(_, style)| (color(cmd.sp), style));
+        let modifiers = [
+            (HiAttr::Bold, "bold"),
+            (HiAttr::Italic, "italic"),
+            (HiAttr::Reverse, "reversed"),
+            (HiAttr::Strikethrough, "crossed_out"),
+        ]
+        .into_iter()
+        .filter(|&(a, _)| attr.contains(a))
+        .map(|(_, m)| m)
+        .collect();
+        Self {
+            fg: color(cmd.fg),
+            bg: color(cmd.bg),
+            underline,
+            modifiers,
+        }
+    }
+}
+
+// Helix theme. Styles of scopes are derived from the highlights of `Colorscheme` and the status line
+// colors are shared with `AirlineTheme`. Colors are referred by the names in [palette] table.
+#[derive(Debug)]
+pub struct HelixTheme<'a> {
+    palette: &'a Palette<'a>,
+    styles: Vec<(&'static str, Style<'a>)>,
+}
+
+impl<'a> HelixTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let colorscheme = Colorscheme::new(palette);
+        let normal = colorscheme.resolve("Normal").unwrap();
+        let airline = AirlineTheme::new(palette);
+        let [normal_label, _, normal_main] = airline.sections("normal");
+        let [insert_label, ..] = airline.sections("insert");
+        let [visual_label, ..] = airline.sections("visual");
+        let [_, _, inactive_main] = airline.sections("inactive");
+
+        let mut styles = vec![
+            (
+                "ui.background",
+                Style {
+                    bg: normal.bg,
+                    ..Default::default()
+                },
+            ),
+            (
+                "ui.text",
+                Style {
+                    fg: normal.fg,
+                    ..Default::default()
+                },
+            ),
+            ("ui.statusline", Style::colors(normal_main)),
+            ("ui.statusline.inactive", Style::colors(inactive_main)),
+            ("ui.statusline.normal", Style::colors(normal_label).bold()),
+            ("ui.statusline.insert", Style::colors(insert_label).bold()),
+            ("ui.statusline.select", Style::colors(visual_label).bold()),
+        ];
+        for &(scope, name) in SCOPES {
+            styles.push((scope, colorscheme.resolve(name).unwrap().into()));
+        }
+
+        Self { palette, styles }
+    }
+
+    fn write_style(&self, w: &mut dyn Write, scope: &str, style: &Style<'_>) -> Result<()> {
+        let mut fields = vec![];
+        if let Some(fg) = style.fg {
+            fields.push(format!("fg = \"{fg}\""));
+        }
+        if let Some(bg) = style.bg {
+            fields.push(format!("bg = \"{bg}\""));
+        }
+        if let Some((color, kind)) = style.underline {
+            match color {
+                Some(c) => fields.push(format!(
+                    "underline = {{ color = \"{c}\", style = \"{kind}\" }}"
+                )),
+                None => fields.push(format!("underline = {{ style = \"{kind}\" }}")),
+            }
+        }
+        if !style.modifiers.is_empty() {
+            let modifiers: Vec<_> = style.modifiers.iter().map(|m| format!("\"{m}\"")).collect();
+            fields.push(format!("modifiers = [{}]", modifiers.join(", ")));
+        }
+        writeln!(w, "\"{scope}\" = {{ {} }}", fields.join(", "))
+    }
+}
+
+impl ThemeWriter for HelixTheme<'_> {
+    fn target(&self) -> &'static str {
+        "helix"
+    }
+
+    fn path(&self) -> PathBuf {
+        theme::path(&["helix", &format!("{}.toml", self.palette.variant().ident())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(
+            w,
+            r#"# Helix theme for {name} colorscheme
+#
+# Author: rhysd <lin90162@yahoo.co.jp>
+# License: MIT
+#   Copyright (c) 2016 rhysd
+#
+# PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+# Generated by script vim-color-spring-night/gen/{source}
+"#,
+            name = self.palette.variant().name(),
+            source = file!(),
+        )?;
+
+        for (scope, style) in &self.styles {
+            self.write_style(w, scope, style)?;
+        }
+
+        writeln!(w)?;
+        writeln!(w, "[palette]")?;
+        let mut colors: Vec<_> = self.palette.iter().collect();
+        colors.sort_by_key(|(&k, _)| k); // Sort by color name to avoid random order
+        for (name, color) in colors {
+            writeln!(w, "{name} = \"{}\"", color.gui.normal())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use std::str;
+    use toml_edit::{DocumentMut, InlineTable};
+
+    fn assert_theme(palette: &Palette) {
+        let w = HelixTheme::new(palette);
+        let mut out = vec![];
+        w.write_to(&mut out).unwrap();
+        let doc: DocumentMut = str::from_utf8(&out).unwrap().parse().unwrap();
+
+        let hex_color = Regex::new(r"^#[[:xdigit:]]{6}$").unwrap();
+        let colors = doc["palette"].as_table().unwrap();
+        for (name, color) in palette.iter() {
+            let value = colors.get(name).and_then(|c| c.as_str()).expect(name);
+            assert!(hex_color.is_match(value), "{name}: {value:?}");
+            assert_eq!(value, *color.gui.normal(), "{name}");
+        }
+        assert_eq!(colors.len(), palette.len());
+
+        let assert_color = |scope: &str, style: &InlineTable, key: &str| {
+            if let Some(c) = style.get(key) {
+                let c = c.as_str().unwrap();
+                assert!(colors.contains_key(c), "{scope}.{key}: {c:?}");
+            }
+        };
+        let mut scopes = 0;
+        for (scope, style) in doc.iter().filter(|(k, _)| *k != "palette") {
+            let style = style.as_inline_table().expect(scope);
+            assert!(!style.is_empty(), "{scope}");
+            for (key, value) in style.iter() {
+                match key {
+                    "fg" | "bg" => assert_color(scope, style, key),
+                    "underline" => {
+                        let underline = value.as_inline_table().unwrap();
+                        assert_color(scope, underline, "color");
+                        assert!(underline.get("style").is_some(), "{scope}");
+                    }
+                    "modifiers" => {
+                        for m in value.as_array().unwrap() {
+                            let m = m.as_str().unwrap();
+                            assert!(
+                                ["bold", "italic", "reversed", "crossed_out"].contains(&m),
+                                "{scope}: {m}"
+                            );
+                        }
+                    }
+                    _ => panic!("unexpected key {key:?} in {scope}"),
+                }
+            }
+            scopes += 1;
+        }
+        assert_eq!(scopes, SCOPES.len() + 7);
+
+        let style = |scope: &str| doc[scope].as_inline_table().unwrap().clone();
+        assert_eq!(
+            style("ui.background").get("bg").unwrap().as_str(),
+            Some("bg")
+        );
+        assert_eq!(
+            style("ui.statusline.insert").get("bg").unwrap().as_str(),
+            Some("skyblue"),
+        );
+        assert_eq!(
+            style("ui.selection").get("bg").unwrap().as_str(),
+            Some("yaezakura")
+        );
+        assert_eq!(style("string").get("fg").unwrap().as_str(), Some("green"));
+    }
+
+    #[test]
+    fn test_default_theme() {
+        assert_theme(&Palette::default());
+    }
+
+    #[test]
+    fn test_morning_theme() {
+        assert_theme(&Palette::morning());
+    }
+}
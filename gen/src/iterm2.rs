@@ -0,0 +1,249 @@
+use crate::alacritty::AlacrittyTheme;
+use crate::color::Rgb;
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::fmt;
+use std::io::{Result, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorSpace {
+    Srgb,
+    #[default]
+    DisplayP3,
+}
+
+impl fmt::Display for ColorSpace {
+    // Values of "Color Space" key in .itermcolors
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Srgb => write!(f, "sRGB"),
+            Self::DisplayP3 => write!(f, "P3"),
+        }
+    }
+}
+
+impl FromStr for ColorSpace {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "srgb" => Ok(Self::Srgb),
+            "p3" => Ok(Self::DisplayP3),
+            _ => Err(format!(
+                "Unknown color space {s:?}. It must be one of \"srgb\" or \"p3\""
+            )),
+        }
+    }
+}
+
+// iTerm2 color preset. Colors are shared with `AlacrittyTheme`
+#[derive(Debug)]
+pub struct ITerm2Theme<'a> {
+    palette: &'a Palette<'a>,
+    color_space: ColorSpace,
+    colors: Vec<(String, &'a str)>,
+}
+
+impl<'a> ITerm2Theme<'a> {
+    pub fn new(palette: &'a Palette, color_space: ColorSpace) -> Self {
+        let alacritty = AlacrittyTheme::new(palette);
+        let (selected_text, selection) = alacritty.selection();
+
+        let mut colors: Vec<_> = alacritty
+            .ansi_colors()
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| (format!("Ansi {i} Color"), name))
+            .collect();
+        for (key, name) in [
+            ("Background Color", alacritty.background()),
+            ("Foreground Color", alacritty.foreground()),
+            ("Bold Color", alacritty.bright_foreground()),
+            ("Cursor Color", alacritty.foreground()),
+            ("Cursor Text Color", alacritty.background()),
+            ("Selection Color", selection),
+            ("Selected Text Color", selected_text),
+        ] {
+            colors.push((key.to_string(), name));
+        }
+
+        Self {
+            palette,
+            color_space,
+            colors,
+        }
+    }
+
+    fn write_header(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(
+            w,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<!--
+  iTerm2 color preset for {name} colorscheme
+
+  Author: rhysd <lin90162@yahoo.co.jp>
+  License: MIT
+    Copyright (c) 2016 rhysd
+
+  PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+  Generated by script vim-color-spring-night/gen/{source}
+-->
+<plist version="1.0">
+<dict>"#,
+            name = self.palette.variant().name(),
+            source = file!(),
+        )
+    }
+
+    fn write_color(&self, w: &mut dyn Write, key: &str, name: &str) -> Result<()> {
+        let rgb = Rgb::from_hex(self.palette[name].gui.normal()).unwrap();
+        let [r, g, b] = match self.color_space {
+            ColorSpace::Srgb => rgb.srgb(),
+            ColorSpace::DisplayP3 => rgb.display_p3(),
+        };
+        writeln!(w, "\t<key>{key}</key>")?;
+        writeln!(w, "\t<dict>")?;
+        writeln!(w, "\t\t<key>Alpha Component</key>")?;
+        writeln!(w, "\t\t<real>1</real>")?;
+        writeln!(w, "\t\t<key>Blue Component</key>")?;
+        writeln!(w, "\t\t<real>{b}</real>")?;
+        writeln!(w, "\t\t<key>Color Space</key>")?;
+        writeln!(w, "\t\t<string>{}</string>", self.color_space)?;
+        writeln!(w, "\t\t<key>Green Component</key>")?;
+        writeln!(w, "\t\t<real>{g}</real>")?;
+        writeln!(w, "\t\t<key>Red Component</key>")?;
+        writeln!(w, "\t\t<real>{r}</real>")?;
+        writeln!(w, "\t</dict>")
+    }
+}
+
+impl ThemeWriter for ITerm2Theme<'_> {
+    fn target(&self) -> &'static str {
+        "iterm2"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&["iterm2", &format!("{}.itermcolors", variant.name())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        self.write_header(w)?;
+        for (key, name) in &self.colors {
+            self.write_color(w, key, name)?;
+        }
+        writeln!(w, "</dict>")?;
+        writeln!(w, "</plist>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::str;
+
+    // Parse the plist into a map from keys to (color space, [r, g, b])
+    fn parse_plist(src: &str) -> HashMap<String, (String, [f64; 3])> {
+        let opts = roxmltree::ParsingOptions {
+            allow_dtd: true,
+            ..Default::default()
+        };
+        let doc = roxmltree::Document::parse_with_options(src, opts).unwrap();
+        let root = doc.root_element();
+        assert_eq!(root.tag_name().name(), "plist");
+        let dict = root.children().find(|n| n.is_element()).unwrap();
+        assert_eq!(dict.tag_name().name(), "dict");
+
+        let mut colors = HashMap::new();
+        let mut children = dict.children().filter(|n| n.is_element());
+        while let Some(key) = children.next() {
+            assert_eq!(key.tag_name().name(), "key");
+            let value = children.next().unwrap();
+            assert_eq!(value.tag_name().name(), "dict");
+
+            let mut entries = HashMap::new();
+            let mut items = value.children().filter(|n| n.is_element());
+            while let Some(k) = items.next() {
+                let v = items.next().unwrap();
+                entries.insert(k.text().unwrap(), v.text().unwrap());
+            }
+            assert_eq!(entries["Alpha Component"], "1");
+            let component = |k: &str| -> f64 {
+                let c = entries[k].parse().unwrap();
+                assert!((0.0..=1.0).contains(&c), "{k}: {c}");
+                c
+            };
+            let rgb = [
+                component("Red Component"),
+                component("Green Component"),
+                component("Blue Component"),
+            ];
+            let space = entries["Color Space"].to_string();
+            colors.insert(key.text().unwrap().to_string(), (space, rgb));
+        }
+        colors
+    }
+
+    #[test]
+    fn test_write_plist() {
+        for palette in [Palette::default(), Palette::morning()] {
+            for color_space in [ColorSpace::Srgb, ColorSpace::DisplayP3] {
+                let w = ITerm2Theme::new(&palette, color_space);
+                let mut out = vec![];
+                w.write_to(&mut out).unwrap();
+                let colors = parse_plist(str::from_utf8(&out).unwrap());
+
+                let mut keys: Vec<_> = (0..16).map(|i| format!("Ansi {i} Color")).collect();
+                for key in [
+                    "Background",
+                    "Foreground",
+                    "Bold",
+                    "Cursor",
+                    "Cursor Text",
+                    "Selection",
+                    "Selected Text",
+                ] {
+                    keys.push(format!("{key} Color"));
+                }
+                assert_eq!(colors.len(), keys.len());
+                for key in &keys {
+                    let (space, _) = colors.get(key).expect(key);
+                    assert_eq!(*space, color_space.to_string());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_conversion() {
+        let palette = Palette::default();
+        let bg = Rgb::from_hex(palette["bg"].gui.normal()).unwrap();
+
+        let w = ITerm2Theme::new(&palette, ColorSpace::Srgb);
+        let mut out = vec![];
+        w.write_to(&mut out).unwrap();
+        let colors = parse_plist(str::from_utf8(&out).unwrap());
+        let (_, rgb) = colors["Background Color"];
+        assert_eq!(rgb, bg.srgb());
+        assert_eq!(rgb.map(|c| (c * 255.0).round() as u8), [bg.r, bg.g, bg.b],);
+
+        let w = ITerm2Theme::new(&palette, ColorSpace::DisplayP3);
+        let mut out = vec![];
+        w.write_to(&mut out).unwrap();
+        let colors = parse_plist(str::from_utf8(&out).unwrap());
+        let (_, rgb) = colors["Background Color"];
+        assert_eq!(rgb, bg.display_p3());
+        assert_ne!(rgb, bg.srgb());
+    }
+
+    #[test]
+    fn test_parse_color_space() {
+        assert_eq!("srgb".parse(), Ok(ColorSpace::Srgb));
+        assert_eq!("p3".parse(), Ok(ColorSpace::DisplayP3));
+        assert!("rgb".parse::<ColorSpace>().is_err());
+    }
+}
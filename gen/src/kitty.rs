@@ -0,0 +1,237 @@
+use crate::alacritty::AlacrittyTheme;
+use crate::colorscheme::Colorscheme;
+use crate::contrast::ColorPair;
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+type Color<'a> = (&'a str, &'a str); // Pair of foreground/background colors
+
+// kitty theme. ANSI colors and selection colors are shared with `AlacrittyTheme`. Tabs, URL, borders
+// and marks follow TabLineSel, TabLine, Underlined, FloatBorder, VertSplit, Search, IncSearch and
+// Visual highlights of `Colorscheme`
+#[derive(Debug)]
+pub struct KittyTheme<'a> {
+    palette: &'a Palette<'a>,
+    ansi: [&'a str; 16],
+    primary: Color<'a>,
+    cursor: Color<'a>,
+    selection: Color<'a>,
+    url: &'a str,
+    active_tab: Color<'a>,
+    inactive_tab: Color<'a>,
+    active_border: &'a str,
+    inactive_border: &'a str,
+    marks: [Color<'a>; 3],
+}
+
+impl<'a> KittyTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let alacritty = AlacrittyTheme::new(palette);
+        let (fg, bg) = (alacritty.foreground(), alacritty.background());
+        let colorscheme = Colorscheme::new(palette);
+        let hl = |name| colorscheme.resolve(name).unwrap();
+        let colors = |name| (hl(name).fg.unwrap(), hl(name).bg.unwrap());
+        // Highlights without foreground color keep the text color
+        let mark = |name| {
+            let hl = hl(name);
+            (hl.fg.filter(|&c| c != "NONE").unwrap_or(fg), hl.bg.unwrap())
+        };
+        Self {
+            palette,
+            ansi: alacritty.ansi_colors(),
+            primary: (fg, bg),
+            cursor: (bg, fg),
+            selection: alacritty.selection(),
+            url: hl("Underlined").fg.unwrap(),
+            active_tab: colors("TabLineSel"),
+            inactive_tab: colors("TabLine"),
+            active_border: hl("FloatBorder").fg.unwrap(),
+            inactive_border: hl("VertSplit").fg.unwrap(),
+            marks: [mark("Search"), mark("IncSearch"), mark("Visual")],
+        }
+    }
+
+    fn color(&self, name: &str) -> &'_ str {
+        self.palette[name].gui.normal()
+    }
+
+    fn write_header(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(
+            w,
+            r#"# kitty theme for {name} colorscheme
+#
+# Author: rhysd <lin90162@yahoo.co.jp>
+# License: MIT
+#   Copyright (c) 2016 rhysd
+#
+# PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+# Generated by script vim-color-spring-night/gen/{source}"#,
+            name = self.palette.variant().name(),
+            source = file!(),
+        )
+    }
+
+    fn write_option(&self, w: &mut dyn Write, key: &str, name: &str) -> Result<()> {
+        writeln!(w, "{key:<28}{}", self.color(name))
+    }
+
+    fn write_colors(&self, w: &mut dyn Write, prefix: &str, (fg, bg): Color<'_>) -> Result<()> {
+        self.write_option(w, &format!("{prefix}foreground"), fg)?;
+        self.write_option(w, &format!("{prefix}background"), bg)
+    }
+
+    /// Pairs of foreground and background colors rendered by kitty
+    pub fn color_pairs(&self) -> Vec<ColorPair<'a>> {
+        let mut pairs = vec![];
+        for (label, (fg, bg)) in [
+            ("primary", self.primary),
+            ("cursor", self.cursor),
+            ("selection", self.selection),
+            ("url", (self.url, self.primary.1)),
+            ("active_tab", self.active_tab),
+            ("inactive_tab", self.inactive_tab),
+        ] {
+            pairs.push(ColorPair::new(label, fg, bg));
+        }
+        for (i, &(fg, bg)) in self.marks.iter().enumerate() {
+            pairs.push(ColorPair::new(format!("mark{}", i + 1), fg, bg));
+        }
+        pairs
+    }
+}
+
+impl ThemeWriter for KittyTheme<'_> {
+    fn target(&self) -> &'static str {
+        "kitty"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&["kitty", &format!("{}.conf", variant.ident())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        self.write_header(w)?;
+
+        writeln!(w)?;
+        self.write_colors(w, "", self.primary)?;
+        self.write_colors(w, "selection_", self.selection)?;
+        self.write_option(w, "cursor", self.cursor.1)?;
+        self.write_option(w, "cursor_text_color", self.cursor.0)?;
+        self.write_option(w, "url_color", self.url)?;
+
+        writeln!(w)?;
+        self.write_colors(w, "active_tab_", self.active_tab)?;
+        self.write_colors(w, "inactive_tab_", self.inactive_tab)?;
+        self.write_option(w, "active_border_color", self.active_border)?;
+        self.write_option(w, "inactive_border_color", self.inactive_border)?;
+
+        writeln!(w)?;
+        for (i, &colors) in self.marks.iter().enumerate() {
+            self.write_colors(w, &format!("mark{}_", i + 1), colors)?;
+        }
+
+        writeln!(w)?;
+        for (i, name) in self.ansi.iter().enumerate() {
+            self.write_option(w, &format!("color{i}"), name)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::tests::assert_palette_colors;
+    use regex::Regex;
+    use std::collections::HashMap;
+    use std::str;
+
+    #[test]
+    fn test_write_theme() {
+        let re_option = Regex::new(r"^([a-z0-9_]+) +(#\w+)$").unwrap();
+
+        for palette in [Palette::default(), Palette::morning()] {
+            let w = KittyTheme::new(&palette);
+            let mut out = vec![];
+            w.write_to(&mut out).unwrap();
+            let rendered = str::from_utf8(&out).unwrap();
+            let num_colors = assert_palette_colors(&palette, rendered);
+
+            let mut options = HashMap::new();
+            for line in rendered.lines() {
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                let found = re_option.captures(line).expect(line);
+                let (key, color) = (found[1].to_string(), found[2].to_string());
+                assert!(
+                    options.insert(key, color).is_none(),
+                    "duplicate option {line:?}"
+                );
+            }
+
+            let mut keys: Vec<_> = (0..16).map(|i| format!("color{i}")).collect();
+            for i in 1..=3 {
+                keys.push(format!("mark{i}_foreground"));
+                keys.push(format!("mark{i}_background"));
+            }
+            for key in [
+                "foreground",
+                "background",
+                "selection_foreground",
+                "selection_background",
+                "cursor",
+                "cursor_text_color",
+                "url_color",
+                "active_tab_foreground",
+                "active_tab_background",
+                "inactive_tab_foreground",
+                "inactive_tab_background",
+                "active_border_color",
+                "inactive_border_color",
+            ] {
+                keys.push(key.to_string());
+            }
+            assert_eq!(options.len(), keys.len(), "{options:?}");
+            assert_eq!(num_colors, keys.len());
+            for key in &keys {
+                assert!(options.contains_key(key), "{key} is missing: {options:?}");
+            }
+
+            // ANSI colors are the same as Alacritty theme
+            let ansi = AlacrittyTheme::new(&palette).ansi_colors();
+            for (i, name) in ansi.iter().enumerate() {
+                assert_eq!(options[&format!("color{i}")], *palette[name].gui.normal());
+            }
+            assert_eq!(options["background"], *palette["bg"].gui.normal());
+
+            // Tabs follow the highlights of tab line
+            let colorscheme = Colorscheme::new(&palette);
+            for (prefix, group) in [("active_tab", "TabLineSel"), ("inactive_tab", "TabLine")] {
+                let hl = colorscheme.resolve(group).unwrap();
+                for (key, name) in [("foreground", hl.fg), ("background", hl.bg)] {
+                    let key = format!("{prefix}_{key}");
+                    assert_eq!(options[&key], *palette[name.unwrap()].gui.normal(), "{key}");
+                }
+            }
+            let border = colorscheme.resolve("FloatBorder").unwrap().fg.unwrap();
+            assert_eq!(
+                options["active_border_color"],
+                *palette[border].gui.normal()
+            );
+
+            // Marks look like search matches and selection in Vim
+            for (i, group) in ["Search", "IncSearch", "Visual"].into_iter().enumerate() {
+                let bg = colorscheme.resolve(group).unwrap().bg.unwrap();
+                let key = format!("mark{}_background", i + 1);
+                assert_eq!(options[&key], *palette[bg].gui.normal(), "{key}");
+                let key = format!("mark{}_foreground", i + 1);
+                assert_eq!(options[&key], options["foreground"], "{key}");
+            }
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use crate::airline::AirlineTheme;
+use crate::contrast::ColorPair;
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+type Color<'a> = (&'a str, &'a str); // Pair of foreground/background colors
+
+#[derive(Debug)]
+struct Section<'a> {
+    mode: &'a str,
+    position: &'a str,
+    colors: Vec<Color<'a>>,
+}
+
+// lightline.vim colorscheme. Colors are shared with `AirlineTheme`
+#[derive(Debug)]
+pub struct LightlineTheme<'a> {
+    palette: &'a Palette<'a>,
+    sections: Vec<Section<'a>>,
+}
+
+impl<'a> LightlineTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let mut sections = vec![];
+        let mut section = |mode, position, colors| {
+            sections.push(Section {
+                mode,
+                position,
+                colors,
+            })
+        };
+
+        // Sections of each mode are shared with the same mode of `AirlineTheme`
+        let airline = AirlineTheme::new(palette);
+        for mode in ["normal", "insert", "visual", "replace", "inactive"] {
+            let [label, info, main] = airline.sections(mode);
+            section(mode, "left", vec![label, info]);
+            section(mode, "middle", vec![main]);
+            section(mode, "right", vec![label, info]);
+        }
+        let [label, info, main] = airline.sections("normal");
+
+        // airline has no terminal and command modes. They differ from normal mode in the label
+        for (mode, label) in [("terminal", ("bg", "blue")), ("command", ("bg", "gold"))] {
+            section(mode, "left", vec![label, info]);
+            section(mode, "middle", vec![main]);
+            section(mode, "right", vec![label, info]);
+        }
+
+        let [error, warning] = airline.error_warning();
+        section("normal", "error", vec![error]);
+        section("normal", "warning", vec![warning]);
+
+        // Tabline looks the same as the status line in normal mode
+        section("tabline", "left", vec![info]);
+        section("tabline", "tabsel", vec![label]);
+        section("tabline", "middle", vec![main]);
+        section("tabline", "right", vec![info]);
+
+        Self { palette, sections }
+    }
+
+    fn write_header(&self, w: &mut dyn Write) -> Result<()> {
+        let variant = self.palette.variant();
+        write!(
+            w,
+            r#"" lightline colorscheme for {name} colorscheme
+"
+" Author: rhysd <lin90162@yahoo.co.jp>
+" License: MIT
+"   Copyright (c) 2016 rhysd
+"
+" PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+" Generated by script vim-color-spring-night/gen/{source}
+
+"#,
+            source = file!(),
+            name = variant.name(),
+        )
+    }
+
+    fn write_section(&self, w: &mut dyn Write, section: &Section<'_>) -> Result<()> {
+        write!(w, "let s:p.{}.{} = [", section.mode, section.position)?;
+        for (i, (fg, bg)) in section.colors.iter().enumerate() {
+            if i > 0 {
+                write!(w, ", ")?;
+            }
+            let fg = &self.palette[fg];
+            let bg = &self.palette[bg];
+            write!(
+                w,
+                "['{gui_fg}', '{gui_bg}', {cterm_fg}, {cterm_bg}]",
+                gui_fg = fg.gui.normal(),
+                gui_bg = bg.gui.normal(),
+                cterm_fg = fg.cterm.normal(),
+                cterm_bg = bg.cterm.normal(),
+            )?;
+        }
+        writeln!(w, "]")
+    }
+
+    /// Pairs of foreground and background colors of all sections in all modes
+    pub fn color_pairs(&self) -> Vec<ColorPair<'a>> {
+        let mut pairs = vec![];
+        for section in &self.sections {
+            for (i, &(fg, bg)) in section.colors.iter().enumerate() {
+                let label = format!("{} {} {}", section.mode, section.position, i);
+                pairs.push(ColorPair::new(label, fg, bg));
+            }
+        }
+        pairs
+    }
+}
+
+impl ThemeWriter for LightlineTheme<'_> {
+    fn target(&self) -> &'static str {
+        "lightline"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&[
+            "autoload",
+            "lightline",
+            "colorscheme",
+            &format!("{}.vim", variant.ident()),
+        ])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        self.write_header(w)?;
+
+        let mut modes = vec![];
+        for section in &self.sections {
+            if !modes.contains(&section.mode) {
+                modes.push(section.mode);
+            }
+        }
+        write!(w, "let s:p = {{")?;
+        for (i, mode) in modes.iter().enumerate() {
+            if i > 0 {
+                write!(w, ", ")?;
+            }
+            write!(w, "'{mode}': {{}}")?;
+        }
+        writeln!(w, "}}")?;
+
+        for section in &self.sections {
+            self.write_section(w, section)?;
+        }
+
+        writeln!(
+            w,
+            "\nlet g:lightline#colorscheme#{}#palette = s:p",
+            self.palette.variant().ident(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::tests::assert_palette_colors;
+    use regex::Regex;
+    use std::collections::HashSet;
+    use std::str;
+
+    #[test]
+    fn test_write_theme() {
+        let re_section =
+            Regex::new(r"^let s:p\.(\w+)\.(left|middle|right|error|warning|tabsel) = \[(.+)\]$")
+                .unwrap();
+        let color = r"\['#\w+', '#\w+', \d{1,3}, \d{1,3}\]";
+        let re_colors = Regex::new(&format!("^{color}(, {color})*$")).unwrap();
+
+        for palette in [Palette::default(), Palette::morning()] {
+            let w = LightlineTheme::new(&palette);
+            let mut out = vec![];
+            w.write_to(&mut out).unwrap();
+            let rendered = str::from_utf8(&out).unwrap();
+            assert_palette_colors(&palette, rendered);
+
+            let ident = palette.variant().ident();
+            assert!(rendered.contains(&format!(
+                "\nlet g:lightline#colorscheme#{ident}#palette = s:p\n"
+            )));
+
+            let mut count = 0;
+            let mut modes = HashSet::new();
+            for line in rendered.lines().filter(|l| l.starts_with("let s:p.")) {
+                let found = re_section.captures(line).expect(line);
+                assert!(re_colors.is_match(&found[3]), "{line}");
+                modes.insert(found[1].to_string());
+                count += 1;
+            }
+            assert_eq!(count, w.sections.len());
+            let expected = [
+                "normal", "insert", "visual", "replace", "inactive", "terminal", "command",
+                "tabline",
+            ];
+            assert_eq!(modes, HashSet::from(expected.map(String::from)));
+            assert!(rendered.contains(
+                "let s:p = {'normal': {}, 'insert': {}, 'visual': {}, 'replace': {}, 'inactive': {}, 'terminal': {}, 'command': {}, 'tabline': {}}\n"
+            ));
+        }
+    }
+
+    #[test]
+    fn test_airline_colors() {
+        for palette in [Palette::default(), Palette::morning()] {
+            let w = LightlineTheme::new(&palette);
+            let mut out = vec![];
+            w.write_to(&mut out).unwrap();
+            let rendered = str::from_utf8(&out).unwrap();
+
+            let color = |(fg, bg): (&str, &str)| {
+                let (fg, bg) = (&palette[fg], &palette[bg]);
+                format!(
+                    "['{}', '{}', {}, {}]",
+                    fg.gui.normal(),
+                    bg.gui.normal(),
+                    fg.cterm.normal(),
+                    bg.cterm.normal(),
+                )
+            };
+            let airline = AirlineTheme::new(&palette);
+            for mode in ["normal", "insert", "visual", "replace", "inactive"] {
+                let [label, info, main] = airline.sections(mode);
+                let (label, info, main) = (color(label), color(info), color(main));
+                for expected in [
+                    format!("let s:p.{mode}.left = [{label}, {info}]\n"),
+                    format!("let s:p.{mode}.middle = [{main}]\n"),
+                    format!("let s:p.{mode}.right = [{label}, {info}]\n"),
+                ] {
+                    assert!(rendered.contains(&expected), "{expected:?}");
+                }
+            }
+            let [_, info, main] = airline.sections("normal");
+            let (info, main) = (color(info), color(main));
+            for (mode, label) in [("terminal", "blue"), ("command", "gold")] {
+                let label = color(("bg", label));
+                for expected in [
+                    format!("let s:p.{mode}.left = [{label}, {info}]\n"),
+                    format!("let s:p.{mode}.middle = [{main}]\n"),
+                    format!("let s:p.{mode}.right = [{label}, {info}]\n"),
+                ] {
+                    assert!(rendered.contains(&expected), "{expected:?}");
+                }
+            }
+
+            let [error, warning] = airline.error_warning();
+            for expected in [
+                format!("let s:p.normal.error = [{}]\n", color(error)),
+                format!("let s:p.normal.warning = [{}]\n", color(warning)),
+            ] {
+                assert!(rendered.contains(&expected), "{expected:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,426 @@
+use crate::colorscheme::{indent, Colorscheme, HiAttr, HiCommand, Highlight};
+use crate::palette::{ColorCode, Palette};
+use crate::theme::{self, ThemeWriter};
+use std::fmt::Display;
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+// Neovim colorscheme written in Lua. It shares highlights and terminal colors with `Colorscheme` and
+// sets them via `vim.api.nvim_set_hl` without building `:highlight` commands at runtime.
+#[derive(Debug)]
+pub struct LuaColorscheme<'a> {
+    palette: &'a Palette<'a>,
+    highlights: &'a [Highlight],
+    term_colors: [&'static str; 16],
+}
+
+impl<'a> LuaColorscheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let colorscheme = Colorscheme::new(palette);
+        Self {
+            palette,
+            highlights: colorscheme.highlights(),
+            term_colors: *colorscheme.term_colors(),
+        }
+    }
+
+    fn write_header(&self, w: &mut dyn Write) -> Result<()> {
+        let variant = self.palette.variant();
+        write!(
+            w,
+            r#"-- {name}: Calm-colored {background} color scheme
+--
+-- Author: rhysd <lin90162@yahoo.co.jp>
+-- License: MIT
+--   Copyright (c) 2016 rhysd
+--
+-- PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+-- Generated by script vim-color-spring-night/gen/{source}
+
+if vim.o.background ~= '{background}' then
+    vim.o.background = '{background}'
+end
+
+if vim.g.colors_name then
+    vim.cmd('hi clear')
+end
+
+if vim.g.syntax_on then
+    vim.cmd('syntax reset')
+end
+
+vim.g.colors_name = '{name}'
+
+local function option(name, default)
+    local value = vim.g[name]
+    if value == nil then
+        value = default
+        vim.g[name] = value
+    end
+    return value ~= 0 and value ~= false
+end
+
+local italic_comments = option('spring_night_italic_comments', 0)
+local kill_italic = option('spring_night_kill_italic', 0)
+local kill_bold = option('spring_night_kill_bold', 0)
+local highlight_terminal = option('spring_night_highlight_terminal', 1)
+local cterm_italic = option('spring_night_cterm_italic', 0)
+
+local gui_running = vim.fn.has('gui_running') == 1
+local true_colors = vim.o.termguicolors
+local undercurl = gui_running
+local fancy_underline = gui_running
+local italic = not kill_italic
+local bold = not kill_bold
+cterm_italic = italic and cterm_italic
+
+if vim.g.spring_night_high_contrast == nil then
+    vim.g.spring_night_high_contrast = (not gui_running and true_colors) and 1 or 0
+end
+local high_contrast = option('spring_night_high_contrast', 0)
+
+local hl = vim.api.nvim_set_hl
+
+"#,
+            name = variant.name(),
+            background = variant.background(),
+            source = file!(),
+        )
+    }
+
+    fn write_contrast_color_variables(&self, w: &mut dyn Write) -> Result<()> {
+        for (name, color) in {
+            let mut v = self.palette.iter().collect::<Vec<_>>();
+            v.sort_by_key(|(&k, _)| k); // Sort by color name to avoid random order
+            v
+        } {
+            if let ColorCode::Contrast(high, low) = color.gui {
+                writeln!(
+                    w,
+                    "local {name}_gui = high_contrast and '{high}' or '{low}'",
+                )?;
+            }
+            if let ColorCode::Contrast(high, low) = color.cterm {
+                writeln!(w, "local {name}_cterm = high_contrast and {high} or {low}")?;
+            }
+        }
+        writeln!(w)
+    }
+
+    fn write_hl_call(&self, w: &mut dyn Write, cmd: &HiCommand, indents: u8) -> Result<()> {
+        fn value(name: &str, suffix: &str, color: &ColorCode<impl Display>, quote: &str) -> String {
+            match color {
+                ColorCode::Normal(c) => format!("{quote}{c}{quote}"),
+                ColorCode::Contrast(..) => format!("{name}_{suffix}"),
+            }
+        }
+
+        let mut args = vec![];
+
+        // Note: Unspecified colors are NONE since nvim_set_hl replaces the entire highlight
+        for (color_name, gui, cterm) in [(&cmd.fg, "fg", "ctermfg"), (&cmd.bg, "bg", "ctermbg")] {
+            if let Some(name) = color_name.filter(|&n| n != "NONE") {
+                let color = &self.palette[name];
+                args.push(format!("{gui} = {}", value(name, "gui", &color.gui, "'")));
+                args.push(format!(
+                    "{cterm} = {}",
+                    value(name, "cterm", &color.cterm, "")
+                ));
+            }
+        }
+
+        if let Some(name) = cmd.sp {
+            let color = &self.palette[name].gui;
+            args.push(format!("sp = {}", value(name, "gui", color, "'")));
+        }
+
+        let mut gui_attrs = vec![];
+        let mut cterm_attrs = vec![];
+        for attr in cmd.attr.iter() {
+            let (gui, cterm) = match attr {
+                HiAttr::Nothing | HiAttr::None => continue,
+                HiAttr::Bold => ("bold = bold", "bold = bold"),
+                HiAttr::Italic => ("italic = italic", "italic = cterm_italic"),
+                HiAttr::Underline => ("underline = true", "underline = true"),
+                HiAttr::Reverse => ("reverse = true", "reverse = true"),
+                HiAttr::CommentItalic => (
+                    "italic = italic_comments and italic",
+                    "italic = italic_comments and cterm_italic",
+                ),
+                HiAttr::Undercurl => ("undercurl = undercurl", "undercurl = undercurl"),
+                HiAttr::Strikethrough => ("strikethrough = true", "strikethrough = true"),
+                HiAttr::Underdouble => (
+                    "underdouble = fancy_underline",
+                    "underdouble = fancy_underline",
+                ),
+                HiAttr::Underdotted => (
+                    "underdotted = fancy_underline",
+                    "underdotted = fancy_underline",
+                ),
+                HiAttr::Underdashed => (
+                    "underdashed = fancy_underline",
+                    "underdashed = fancy_underline",
+                ),
+                HiAttr::Nocombine => ("nocombine = true", "nocombine = true"),
+                HiAttr::Standout => ("standout = true", "standout = true"),
+            };
+            gui_attrs.push(gui);
+            cterm_attrs.push(cterm);
+        }
+
+        // Undercurl and other underline styles fall back to plain underline outside GUI
+        if !cmd.attr.contains(HiAttr::Underline) && cmd.attr.iter().any(HiAttr::is_fancy_underline)
+        {
+            let fallback = if cmd.attr.contains(HiAttr::Undercurl) {
+                "underline = not undercurl"
+            } else {
+                "underline = not fancy_underline"
+            };
+            gui_attrs.push(fallback);
+            cterm_attrs.push(fallback);
+        }
+
+        if !gui_attrs.is_empty() {
+            args.push(gui_attrs.join(", "));
+            args.push(format!("cterm = {{ {} }}", cterm_attrs.join(", ")));
+        }
+
+        write!(w, "{}hl(0, '{}', {{", indent(indents), cmd.name)?;
+        if !args.is_empty() {
+            write!(w, " {} ", args.join(", "))?;
+        }
+        writeln!(w, "}})")
+    }
+
+    fn write_highlights(&self, w: &mut dyn Write) -> Result<()> {
+        for hl in self.highlights {
+            match hl {
+                Highlight::Fixed(hl) => self.write_hl_call(w, hl, 0)?,
+                Highlight::Link { name, to } => {
+                    writeln!(w, "hl(0, '{name}', {{ link = '{to}' }})")?
+                }
+                Highlight::Dynamic { gui, term } => {
+                    writeln!(w, "if gui_running then")?;
+                    self.write_hl_call(w, gui, 1)?;
+                    writeln!(w, "else")?;
+                    self.write_hl_call(w, term, 1)?;
+                    writeln!(w, "end")?;
+                }
+            }
+        }
+        writeln!(w)
+    }
+
+    fn write_term_colors(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(w, "if highlight_terminal then")?;
+        writeln!(w, "{}if gui_running or true_colors then", indent(1))?;
+        for (index, name) in self.term_colors.iter().enumerate() {
+            writeln!(
+                w,
+                "{indent}vim.g.terminal_color_{index} = '{color}'",
+                indent = indent(2),
+                color = self.palette[name].gui.normal(),
+            )?;
+        }
+        writeln!(w, "{}else", indent(1))?;
+        for (index, name) in self.term_colors.iter().enumerate() {
+            writeln!(
+                w,
+                "{indent}vim.g.terminal_color_{index} = {color}",
+                indent = indent(2),
+                color = self.palette[name].cterm.normal(),
+            )?;
+        }
+        writeln!(w, "{}end", indent(1))?;
+        writeln!(
+            w,
+            "{}vim.g.terminal_color_background = vim.g.terminal_color_0",
+            indent(1),
+        )?;
+        writeln!(
+            w,
+            "{}vim.g.terminal_color_foreground = vim.g.terminal_color_7",
+            indent(1),
+        )?;
+        writeln!(w, "end")
+    }
+}
+
+impl ThemeWriter for LuaColorscheme<'_> {
+    fn target(&self) -> &'static str {
+        "lua"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&["colors", &format!("{}.lua", variant.name())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        self.write_header(w)?;
+        self.write_contrast_color_variables(w)?;
+        self.write_highlights(w)?;
+        self.write_term_colors(w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::palette::Color;
+    use std::collections::HashMap;
+    use std::str;
+
+    fn palette() -> Palette<'static> {
+        let mut m = HashMap::new();
+        m.insert(
+            "n",
+            Color {
+                gui: ColorCode::Normal("#123456"),
+                cterm: ColorCode::Normal(123),
+            },
+        );
+        m.insert(
+            "c",
+            Color {
+                gui: ColorCode::Contrast("#123456", "#7890ab"),
+                cterm: ColorCode::Contrast(123, 234),
+            },
+        );
+        Palette::from(m)
+    }
+
+    #[test]
+    fn test_write_header() {
+        let palette = Palette::default();
+        let w = LuaColorscheme::new(&palette);
+        let mut out = vec![];
+        w.write_header(&mut out).unwrap();
+        let rendered = str::from_utf8(&out).unwrap();
+        assert!(rendered.starts_with("-- spring-night: Calm-colored dark color scheme"));
+        assert!(rendered.contains("vim.g.colors_name = 'spring-night'"));
+        assert!(rendered.contains("    vim.o.background = 'dark'\n"));
+
+        let palette = Palette::morning();
+        let w = LuaColorscheme::new(&palette);
+        let mut out = vec![];
+        w.write_header(&mut out).unwrap();
+        let rendered = str::from_utf8(&out).unwrap();
+        assert!(rendered.contains("vim.g.colors_name = 'spring-morning'"));
+        assert!(rendered.contains("    vim.o.background = 'light'\n"));
+    }
+
+    #[test]
+    fn test_write_contrast_color_variables() {
+        let palette = palette();
+        let w = LuaColorscheme::new(&palette);
+        let mut out = vec![];
+        w.write_contrast_color_variables(&mut out).unwrap();
+        assert_eq!(
+            str::from_utf8(&out).unwrap(),
+            "local c_gui = high_contrast and '#123456' or '#7890ab'\nlocal c_cterm = high_contrast and 123 or 234\n\n",
+        );
+    }
+
+    #[test]
+    fn test_write_hl_call() {
+        #[rustfmt::skip]
+        let testcases = vec![
+            ((None, None, None, HiAttr::Nothing),                0, "hl(0, 'HL', {})"),
+            ((Some("n"), None, None, HiAttr::Nothing),           0, "hl(0, 'HL', { fg = '#123456', ctermfg = 123 })"),
+            ((None, Some("n"), None, HiAttr::Nothing),           0, "hl(0, 'HL', { bg = '#123456', ctermbg = 123 })"),
+            ((Some("c"), Some("c"), None, HiAttr::Nothing),      0, "hl(0, 'HL', { fg = c_gui, ctermfg = c_cterm, bg = c_gui, ctermbg = c_cterm })"),
+            ((Some("NONE"), Some("n"), None, HiAttr::Nothing),   0, "hl(0, 'HL', { bg = '#123456', ctermbg = 123 })"),
+            ((None, None, None, HiAttr::None),                   0, "hl(0, 'HL', {})"),
+            ((None, None, None, HiAttr::Bold),                   0, "hl(0, 'HL', { bold = bold, cterm = { bold = bold } })"),
+            ((None, None, None, HiAttr::Italic),                 0, "hl(0, 'HL', { italic = italic, cterm = { italic = cterm_italic } })"),
+            ((None, None, None, HiAttr::Underline),              0, "hl(0, 'HL', { underline = true, cterm = { underline = true } })"),
+            ((None, None, None, HiAttr::Reverse),                0, "hl(0, 'HL', { reverse = true, cterm = { reverse = true } })"),
+            ((None, None, None, HiAttr::CommentItalic),          0, "hl(0, 'HL', { italic = italic_comments and italic, cterm = { italic = italic_comments and cterm_italic } })"),
+            ((None, None, Some("c"), HiAttr::Undercurl),         0, "hl(0, 'HL', { sp = c_gui, undercurl = undercurl, underline = not undercurl, cterm = { undercurl = undercurl, underline = not undercurl } })"),
+            ((Some("n"), None, None, HiAttr::Nothing),           1, "    hl(0, 'HL', { fg = '#123456', ctermfg = 123 })"),
+        ];
+
+        let palette = palette();
+        let w = LuaColorscheme::new(&palette);
+        for ((fg, bg, sp, attr), indent, expected) in testcases {
+            let cmd = HiCommand {
+                name: "HL",
+                fg,
+                bg,
+                sp,
+                attr: attr.into(),
+            };
+            let mut out = vec![];
+            w.write_hl_call(&mut out, &cmd, indent).unwrap();
+            assert_eq!(str::from_utf8(&out).unwrap(), format!("{}\n", expected));
+        }
+
+        #[rustfmt::skip]
+        let testcases = vec![
+            (HiAttr::Bold | HiAttr::Italic,             "hl(0, 'HL', { bold = bold, italic = italic, cterm = { bold = bold, italic = cterm_italic } })"),
+            (HiAttr::Underline | HiAttr::Reverse,       "hl(0, 'HL', { underline = true, reverse = true, cterm = { underline = true, reverse = true } })"),
+            (HiAttr::Strikethrough | HiAttr::Nocombine, "hl(0, 'HL', { strikethrough = true, nocombine = true, cterm = { strikethrough = true, nocombine = true } })"),
+            (HiAttr::Underdashed | HiAttr::Standout,    "hl(0, 'HL', { underdashed = fancy_underline, standout = true, underline = not fancy_underline, cterm = { underdashed = fancy_underline, standout = true, underline = not fancy_underline } })"),
+            (HiAttr::Underline | HiAttr::Underdotted,   "hl(0, 'HL', { underline = true, underdotted = fancy_underline, cterm = { underline = true, underdotted = fancy_underline } })"),
+        ];
+        for (attr, expected) in testcases {
+            let cmd = HiCommand {
+                name: "HL",
+                fg: None,
+                bg: None,
+                sp: None,
+                attr,
+            };
+            let mut out = vec![];
+            w.write_hl_call(&mut out, &cmd, 0).unwrap();
+            assert_eq!(str::from_utf8(&out).unwrap(), format!("{}\n", expected));
+        }
+    }
+
+    #[test]
+    fn test_write_dynamic_highlight() {
+        let palette = palette();
+        let mut w = LuaColorscheme::new(&palette);
+        let cmd = || HiCommand {
+            name: "HL",
+            fg: None,
+            bg: None,
+            sp: None,
+            attr: HiAttr::Nothing.into(),
+        };
+        let dynamic = &[Highlight::Dynamic {
+            gui: cmd(),
+            term: cmd(),
+        }];
+        w.highlights = dynamic;
+        let mut out = vec![];
+        w.write_highlights(&mut out).unwrap();
+        assert_eq!(
+            str::from_utf8(&out).unwrap(),
+            "if gui_running then\n    hl(0, 'HL', {})\nelse\n    hl(0, 'HL', {})\nend\n\n",
+        );
+    }
+
+    #[test]
+    fn test_write_default_colorscheme() {
+        let palette = Palette::default();
+        let w = LuaColorscheme::new(&palette);
+        let mut out = vec![];
+        w.write_to(&mut out).unwrap();
+        let rendered = str::from_utf8(&out).unwrap();
+        assert!(rendered.contains("local bg_gui = high_contrast and '#132132' or '#334152'\n"));
+        assert!(rendered.contains(
+            "hl(0, 'Normal', { fg = '#fffeeb', ctermfg = fg_cterm, bg = bg_gui, ctermbg = 233 })\n"
+        ));
+        assert!(rendered.contains("        vim.g.terminal_color_0 = '#132132'\n"));
+        assert!(rendered.contains("        vim.g.terminal_color_0 = 233\n"));
+        assert!(!rendered.contains("exe"));
+        assert!(
+            rendered.contains("hl(0, '@function.call', { fg = '#fffeeb', ctermfg = fg_cterm })\n")
+        );
+        assert!(rendered.contains(
+            "hl(0, '@lsp.typemod.function.defaultLibrary', { link = '@function.builtin' })\n"
+        ));
+    }
+}
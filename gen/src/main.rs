@@ -1,60 +1,141 @@
 mod airline;
 mod alacritty;
+mod color;
 mod colorscheme;
+mod contrast;
+mod foot;
+mod ghostty;
+mod groups;
+mod helix;
+mod iterm2;
+mod kitty;
+mod lightline;
+mod lua;
 mod palette;
+mod textmate;
+mod theme;
+mod tmux;
+mod vscode;
+mod wezterm;
+mod windows_terminal;
+mod xresources;
+mod zed;
 
-use airline::AirlineTheme;
-use alacritty::AlacrittyTheme;
 use colorscheme::Colorscheme;
+use contrast::ContrastChecker;
+use iterm2::ColorSpace;
 use palette::Palette;
+use theme::ThemeWriter;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use similar::TextDiff;
 use std::env;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{self, BufWriter, Write};
-use std::path::PathBuf;
+use std::path::Path;
+use toml_edit::DocumentMut;
 
-fn write_to_files(dir: &str) -> Result<()> {
-    let palette = Palette::default();
+fn write_to_files(dir: &str, writers: &[Box<dyn ThemeWriter + '_>]) -> Result<()> {
+    for writer in writers {
+        let path = Path::new(dir).join(writer.path());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Could not create directory {:?}", parent))?;
+        }
+        let file = File::create(&path)
+            .with_context(|| format!("Could not create {} file {:?}", writer.target(), &path))?;
+        writer
+            .write_to(&mut BufWriter::new(file))
+            .with_context(|| format!("Could not write to {} file {:?}", writer.target(), &path))?;
+    }
+    Ok(())
+}
+
+fn write_to(w: &mut impl Write, writers: &[Box<dyn ThemeWriter + '_>]) -> Result<()> {
+    for (i, writer) in writers.iter().enumerate() {
+        if i > 0 {
+            writeln!(w)?;
+        }
+        writer.write_to(w)?;
+    }
+    Ok(())
+}
+
+// Compares generated contents with the files under the directory and writes unified diffs of stale
+// files. Returns the number of the stale files.
+fn check_files(
+    w: &mut impl Write,
+    dir: &str,
+    writers: &[Box<dyn ThemeWriter + '_>],
+) -> Result<usize> {
+    let mut stale = 0;
+    for writer in writers {
+        let mut generated = vec![];
+        writer.write_to(&mut generated)?;
+
+        let path = Path::new(dir).join(writer.path());
+        let current = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => {
+                return Err(err).with_context(|| {
+                    format!("Could not read {} file {:?}", writer.target(), &path)
+                })
+            }
+        };
+        if current == generated {
+            continue;
+        }
+
+        let current = String::from_utf8_lossy(&current);
+        let generated = String::from_utf8_lossy(&generated);
+        let name = writer.path().display().to_string();
+        let diff = TextDiff::from_lines(current.as_ref(), generated.as_ref());
+        write!(
+            w,
+            "{}",
+            diff.unified_diff()
+                .header(&format!("a/{name}"), &format!("b/{name}")),
+        )?;
+        stale += 1;
+    }
+    Ok(stale)
+}
 
-    fn join(entries: &[&str]) -> PathBuf {
-        let mut entries = entries.iter();
-        let mut path = PathBuf::from(entries.next().unwrap());
-        for entry in entries {
-            path.push(entry);
+fn list_targets(w: &mut impl Write, palettes: &[Palette], color_space: ColorSpace) -> Result<()> {
+    let mut targets = vec![];
+    for palette in palettes {
+        let variant = palette.variant().name();
+        for writer in theme::writers(palette, color_space) {
+            targets.push((format!("{}:{}", writer.target(), variant), writer.path()));
         }
-        path
-    }
-
-    let path = join(&[dir, "colors", "spring-night.vim"]);
-    let file = File::create(&path)
-        .with_context(|| format!("Could not create colorscheme file: {:?}", &path))?;
-    Colorscheme::new(&palette)
-        .write_to(&mut BufWriter::new(file))
-        .with_context(|| format!("Could not write to colorscheme file {:?}", &path))?;
-
-    let path = join(&[dir, "autoload", "airline", "themes", "spring_night.vim"]);
-    let file = File::create(&path)
-        .with_context(|| format!("Could not create airline theme file {:?}", &path))?;
-    AirlineTheme::new(&palette)
-        .write_to(&mut BufWriter::new(file))
-        .with_context(|| format!("Could not write to airline theme file {:?}", &path))?;
-
-    let path = join(&[dir, "alacritty", "spring_night.toml"]);
-    let file = File::create(&path)
-        .with_context(|| format!("Could not create alacritty theme file {:?}", &path))?;
-    AlacrittyTheme::new(&palette)
-        .write_to(&mut BufWriter::new(file))
-        .with_context(|| format!("Could not write to alacritty theme file {:?}", &path))
+    }
+    let width = targets.iter().map(|(t, _)| t.len()).max().unwrap_or(0);
+    for (target, path) in targets {
+        writeln!(w, "{target:<width$} {}", path.display())?;
+    }
+    Ok(())
 }
 
-fn write_to(w: &mut impl Write) -> Result<()> {
-    let palette = Palette::default();
-    Colorscheme::new(&palette).write_to(w)?;
-    writeln!(w)?;
-    AirlineTheme::new(&palette).write_to(w)?;
-    writeln!(w)?;
-    AlacrittyTheme::new(&palette).write_to(w)?;
+fn report_cterm_mismatches(w: &mut impl Write, palettes: &[Palette], threshold: f64) -> Result<()> {
+    use color::Rgb;
+
+    for palette in palettes {
+        let variant = palette.variant().name();
+        for m in palette.cterm_mismatches(threshold) {
+            writeln!(
+                w,
+                "{variant}: '{name}' {gui} is mapped to {cterm} ({cterm_hex}) but the nearest is {nearest} ({nearest_hex}) (distance: {distance:.1})",
+                name = m.name,
+                gui = m.gui,
+                cterm = m.cterm,
+                cterm_hex = Rgb::from_xterm(m.cterm).hex(),
+                nearest = m.nearest,
+                nearest_hex = Rgb::from_xterm(m.nearest).hex(),
+                distance = m.distance,
+            )?;
+        }
+    }
     Ok(())
 }
 
@@ -66,6 +147,56 @@ fn main() -> Result<()> {
 
     let mut opts = getopts::Options::new();
     opts.optopt("d", "dir", "repository root directory", "PATH");
+    opts.optopt(
+        "p",
+        "palette",
+        "load color palette from TOML or JSON file",
+        "PATH",
+    );
+    opts.optflag(
+        "",
+        "check-contrast",
+        "report highlights and theme colors whose contrast ratio is lower than threshold",
+    );
+    opts.optopt(
+        "",
+        "contrast-threshold",
+        "threshold of WCAG 2 contrast ratio for --check-contrast (default: 4.5)",
+        "RATIO",
+    );
+    opts.optflag(
+        "",
+        "cterm-report",
+        "report cterm colors which are far from the nearest 256 colors of GUI colors",
+    );
+    opts.optopt(
+        "",
+        "cterm-threshold",
+        "threshold of color distance (CIEDE2000) for --cterm-report (default: 10)",
+        "NUM",
+    );
+    opts.optopt(
+        "",
+        "iterm2-color-space",
+        "color space of iTerm2 color presets: \"srgb\" or \"p3\" (default: p3)",
+        "SPACE",
+    );
+    opts.optmulti(
+        "t",
+        "target",
+        "generate only the target. NAME is a target name such as \"vim\" or a name with variant such as \"vim:spring-night\". Can be specified multiple times",
+        "NAME",
+    );
+    opts.optflag(
+        "",
+        "check",
+        "check files under --dir are up-to-date without writing them. Diffs of stale files are reported",
+    );
+    opts.optflag(
+        "",
+        "list-targets",
+        "list names and output paths of all targets",
+    );
     opts.optflag("h", "help", "print this help");
     let opts = opts;
 
@@ -79,10 +210,98 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    let doc: DocumentMut;
+    let mut palettes = [Palette::default(), Palette::morning()];
+    if let Some(path) = matches.opt_str("p") {
+        let src = fs::read_to_string(&path)
+            .with_context(|| format!("Could not read palette file {:?}", &path))?;
+        // JSON palette is converted into TOML document by its file extension
+        doc = if path.ends_with(".json") {
+            palette::toml_from_json(&src)
+                .with_context(|| format!("Could not parse palette file {:?} as JSON", &path))?
+        } else {
+            src.parse()
+                .with_context(|| format!("Could not parse palette file {:?} as TOML", &path))?
+        };
+        let palette = Palette::from_toml(&doc)
+            .with_context(|| format!("Invalid palette file {:?}", &path))?;
+        // The palette replaces the builtin one for the same variant
+        for p in palettes.iter_mut() {
+            if p.variant() == palette.variant() {
+                *p = palette;
+                break;
+            }
+        }
+    }
+
+    if matches.opt_present("check-contrast") {
+        let threshold = match matches.opt_str("contrast-threshold") {
+            Some(t) => t
+                .parse()
+                .with_context(|| format!("Invalid --contrast-threshold value {t:?}"))?,
+            None => 4.5,
+        };
+        let mut stdout = io::stdout().lock();
+        let mut issues = 0;
+        for palette in &palettes {
+            issues += ContrastChecker::new(palette, threshold)
+                .write_report(&mut stdout)
+                .context("Could not write to stdout")?;
+        }
+        if issues > 0 {
+            bail!("{issues} color pair(s) have contrast ratio lower than {threshold}");
+        }
+        return Ok(());
+    }
+
+    if matches.opt_present("cterm-report") {
+        let threshold = match matches.opt_str("cterm-threshold") {
+            Some(t) => t
+                .parse()
+                .with_context(|| format!("Invalid --cterm-threshold value {t:?}"))?,
+            None => 10.0,
+        };
+        return report_cterm_mismatches(&mut io::stdout().lock(), &palettes, threshold)
+            .context("Could not write to stdout");
+    }
+
+    // Highlights are shared by all palettes
+    let unknown = groups::unknown_groups(Colorscheme::new(&palettes[0]).highlights());
+    if !unknown.is_empty() {
+        bail!(
+            "Unknown highlight groups which are not builtin nor in known namespaces: {}",
+            unknown.join(", "),
+        );
+    }
+
+    let color_space = match matches.opt_str("iterm2-color-space") {
+        Some(s) => s.parse().map_err(anyhow::Error::msg)?,
+        None => ColorSpace::default(),
+    };
+
+    if matches.opt_present("list-targets") {
+        return list_targets(&mut io::stdout().lock(), &palettes, color_space)
+            .context("Could not write to stdout");
+    }
+
+    let writers = theme::select(&palettes, color_space, &matches.opt_strs("t"))
+        .context("Please try --list-targets to know all targets")?;
+
+    if matches.opt_present("check") {
+        let Some(dir) = matches.opt_str("d") else {
+            bail!("--check option requires --dir option");
+        };
+        let stale = check_files(&mut io::stdout().lock(), &dir, &writers)?;
+        if stale > 0 {
+            bail!("{stale} generated file(s) are not up-to-date. Please run `cargo run -- --dir {dir}` to update them");
+        }
+        return Ok(());
+    }
+
     if let Some(dir) = matches.opt_str("d") {
-        write_to_files(&dir)
+        write_to_files(&dir, &writers)
     } else {
-        write_to(&mut io::stdout().lock()).context("Could not write to stdout")
+        write_to(&mut io::stdout().lock(), &writers).context("Could not write to stdout")
     }
 }
 
@@ -93,7 +312,93 @@ mod tests {
     #[test]
     fn test_write_to_stdout_successfully() {
         let mut stdout = vec![];
-        write_to(&mut stdout).unwrap();
+        let palettes = [Palette::default(), Palette::morning()];
+        let writers = theme::select(&palettes, ColorSpace::default(), &[]).unwrap();
+        write_to(&mut stdout, &writers).unwrap();
         assert!(!stdout.is_empty());
     }
+
+    #[test]
+    fn test_check_files() {
+        let dir = env::temp_dir().join(format!("spring-night-check-{}", std::process::id()));
+        let palettes = [Palette::default(), Palette::morning()];
+        let writers = theme::select(&palettes, ColorSpace::default(), &[]).unwrap();
+        let dir_str = dir.to_str().unwrap();
+        write_to_files(dir_str, &writers).unwrap();
+
+        let mut out = vec![];
+        assert_eq!(check_files(&mut out, dir_str, &writers).unwrap(), 0);
+        assert!(out.is_empty(), "{}", String::from_utf8_lossy(&out));
+
+        // Modify one file and remove another file
+        let modified = dir.join("colors").join("spring-night.vim");
+        let src = fs::read_to_string(&modified).unwrap();
+        let src = src.replace(
+            "let g:colors_name = 'spring-night'",
+            "let g:colors_name = 'foo'",
+        );
+        fs::write(&modified, src).unwrap();
+        fs::remove_file(dir.join("alacritty").join("spring_morning.toml")).unwrap();
+
+        let mut out = vec![];
+        let stale = check_files(&mut out, dir_str, &writers);
+        let out = String::from_utf8(out).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(stale.unwrap(), 2, "{out}");
+        assert!(
+            out.contains("--- a/colors/spring-night.vim\n+++ b/colors/spring-night.vim\n"),
+            "{out}"
+        );
+        assert!(
+            out.contains("\n-let g:colors_name = 'foo'\n+let g:colors_name = 'spring-night'\n"),
+            "{out}"
+        );
+        assert!(
+            out.contains("--- a/alacritty/spring_morning.toml\n"),
+            "{out}"
+        );
+        assert!(
+            out.contains("\n+# Alacritty theme for spring-morning colorscheme\n"),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn test_list_targets() {
+        let mut out = vec![];
+        let palettes = [Palette::default(), Palette::morning()];
+        list_targets(&mut out, &palettes, ColorSpace::default()).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+        assert!(
+            lines.contains(&"vim:spring-night                colors/spring-night.vim"),
+            "{out}"
+        );
+        assert!(
+            lines.contains(&"alacritty:spring-morning        alacritty/spring_morning.toml"),
+            "{out}"
+        );
+        // Paths are aligned after the longest target
+        assert!(
+            lines.contains(&"windows-terminal:spring-morning windows-terminal/spring-morning.json"),
+            "{out}"
+        );
+        let column = lines[0].find(" colors/").unwrap();
+        for line in &lines {
+            assert_eq!(line.as_bytes()[column], b' ', "{line}");
+            assert_ne!(line.as_bytes()[column + 1], b' ', "{line}");
+        }
+    }
+
+    #[test]
+    fn test_report_cterm_mismatches() {
+        let mut out = vec![];
+        report_cterm_mismatches(&mut out, &[Palette::default()], 10.0).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(
+            out.contains("spring-night: 'dullgold' #b6955b is mapped to 221 (#ffd75f) but the nearest is 137 (#af875f)"),
+            "{out}",
+        );
+    }
 }
@@ -1,6 +1,9 @@
+use crate::color::Rgb;
+use anyhow::{bail, Context, Result};
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::ops::Deref;
+use toml_edit::{Array, DocumentMut, InlineTable, Item, Value};
 
 #[derive(Debug, PartialEq)]
 pub struct Color<'a> {
@@ -21,24 +24,79 @@ impl<T: Display> ColorCode<T> {
             ColorCode::Contrast(h, _) => h,
         }
     }
+
+    pub fn map<U: Display>(&self, f: impl Fn(&T) -> U) -> ColorCode<U> {
+        match self {
+            ColorCode::Normal(c) => ColorCode::Normal(f(c)),
+            ColorCode::Contrast(h, l) => ColorCode::Contrast(f(h), f(l)),
+        }
+    }
+}
+
+// Hand-picked cterm color which is perceptually far from the nearest xterm-256 color of its GUI color
+#[derive(Debug, PartialEq)]
+pub struct CtermMismatch<'a> {
+    pub name: &'a str,
+    pub gui: &'a str,
+    pub cterm: u8,
+    pub nearest: u8,
+    pub distance: f64, // CIEDE2000 distance between the cterm color and the nearest color
 }
 
 type Colors<'a> = HashMap<&'a str, Color<'a>>;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    Night,   // Dark colorscheme
+    Morning, // Light colorscheme
+}
+
+impl Variant {
+    // Name of the colorscheme used for `:colorscheme` command
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Night => "spring-night",
+            Self::Morning => "spring-morning",
+        }
+    }
+
+    // Name of the colorscheme used in file names and Vim script identifiers
+    pub fn ident(self) -> &'static str {
+        match self {
+            Self::Night => "spring_night",
+            Self::Morning => "spring_morning",
+        }
+    }
+
+    // Value of Vim's 'background' option
+    pub fn background(self) -> &'static str {
+        match self {
+            Self::Night => "dark",
+            Self::Morning => "light",
+        }
+    }
+}
+
 #[derive(Debug)]
-pub struct Palette<'a>(Colors<'a>);
+pub struct Palette<'a> {
+    variant: Variant,
+    colors: Colors<'a>,
+}
 
 impl<'a> Deref for Palette<'a> {
     type Target = Colors<'a>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.colors
     }
 }
 
 impl<'a> From<Colors<'a>> for Palette<'a> {
-    fn from(m: Colors<'a>) -> Self {
-        Self(m)
+    fn from(colors: Colors<'a>) -> Self {
+        Self {
+            variant: Variant::Night,
+            colors,
+        }
     }
 }
 
@@ -96,7 +154,229 @@ impl Default for Palette<'_> {
         color("whitered",   Normal("#ffbfaf"),              Normal(217));
         color("inu",        Normal("#ddbc96"),              Normal(180));
 
-        Self(table)
+        Self::from(table)
+    }
+}
+
+fn is_hex_color(s: &str) -> bool {
+    s.len() == 7 && s.starts_with('#') && s[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Convert a palette written in JSON into a TOML document so that it can be loaded by
+/// `Palette::from_toml`. The JSON object has the same structure as the TOML file.
+pub fn toml_from_json(src: &str) -> Result<DocumentMut> {
+    fn convert(json: &serde_json::Value) -> Result<Value> {
+        use serde_json::Value as Json;
+        Ok(match json {
+            Json::String(s) => s.as_str().into(),
+            Json::Number(n) => match n.as_i64() {
+                Some(i) => i.into(),
+                None => bail!("Number {n} is not an integer"),
+            },
+            Json::Bool(b) => (*b).into(),
+            Json::Array(a) => a.iter().map(convert).collect::<Result<Array>>()?.into(),
+            Json::Object(o) => {
+                let mut table = InlineTable::new();
+                for (k, v) in o {
+                    table.insert(k, convert(v)?);
+                }
+                table.into()
+            }
+            Json::Null => bail!("null is not allowed"),
+        })
+    }
+
+    let json: serde_json::Value = serde_json::from_str(src)?;
+    let serde_json::Value::Object(obj) = json else {
+        bail!("Palette must be a JSON object");
+    };
+    let mut doc = DocumentMut::new();
+    for (key, value) in &obj {
+        let value = convert(value).with_context(|| format!("Invalid value at '{key}'"))?;
+        doc.insert(key, Item::Value(value));
+    }
+    Ok(doc)
+}
+
+// A color code is either a single value or a table of high and low contrast values like
+// `{ high = "#132132", low = "#334152" }`.
+fn parse_color_code<'a, T: Display>(
+    item: &'a Item,
+    parse: impl Fn(&'a Value) -> Option<T>,
+) -> Option<ColorCode<T>> {
+    let Some(table) = item.as_table_like() else {
+        return item.as_value().and_then(parse).map(ColorCode::Normal);
+    };
+    if table.len() != 2 {
+        return None;
+    }
+    let high = table.get("high")?.as_value().and_then(&parse)?;
+    let low = table.get("low")?.as_value().and_then(&parse)?;
+    Some(ColorCode::Contrast(high, low))
+}
+
+impl<'a> Palette<'a> {
+    /// Palette for the light variant "spring-morning". It defines the same color names as
+    /// `Palette::default` so that all highlights and themes can be shared between both variants.
+    #[rustfmt::skip]
+    pub fn morning() -> Self {
+        use ColorCode::{Normal, Contrast};
+
+        let mut table = HashMap::new();
+        let mut color = |name, gui, cterm| {
+            assert_eq!(table.insert(name, Color { gui, cterm }), None);
+        };
+
+        color("bg",         Contrast("#fbf8ef", "#f3eee0"), Normal(231));
+        color("bgweaker",   Contrast("#f1ece0", "#e8e2d2"), Normal(255));
+        color("bgemphasis", Normal("#ebe5d6"),              Normal(254));
+        color("bglight",    Normal("#e2dbc9"),              Normal(253));
+        color("bgstrong",   Normal("#d6cfbb"),              Normal(187));
+        color("light",      Normal("#b3ac9c"),              Normal(145));
+        color("fg",         Normal("#2a3644"),              Contrast(236, 238));
+        color("hiddenfg",   Normal("#c3ccd6"),              Normal(251));
+        color("weakfg",     Normal("#707d8b"),              Normal(244));
+        color("weakerfg",   Normal("#939ba3"),              Normal(247));
+        color("black",      Normal("#28323c"),              Normal(236));
+        color("gray",       Normal("#7b8590"),              Normal(102));
+        color("white",      Normal("#fdfdf8"),              Normal(231));
+        color("nasu",       Normal("#ddd2ef"),              Normal(189));
+        color("fuchsia",    Normal("#8b6bb0"),              Normal(97));
+        color("purple",     Normal("#7a4eaf"),              Normal(97));
+        color("yaezakura",  Normal("#f2d4de"),              Normal(224));
+        color("sakura",     Normal("#e4aabb"),              Normal(181));
+        color("kakezakura", Normal("#c25b78"),              Normal(168));
+        color("palepink",   Normal("#a3695b"),              Normal(131));
+        color("mikan",      Normal("#d65f2e"),              Normal(166));
+        color("orange",     Normal("#bf6a3c"),              Normal(173));
+        color("darkgreen",  Normal("#c5e3bf"),              Normal(151));
+        color("green",      Normal("#4c8a3a"),              Normal(71));
+        color("lime",       Normal("#5a9a1c"),              Normal(64));
+        color("blue",       Normal("#3c66c2"),              Normal(25));
+        color("paleblue",   Normal("#5a80c8"),              Normal(68));
+        color("cloudy",     Normal("#6a8aa9"),              Normal(67));
+        color("skyblue",    Normal("#2d7db0"),              Normal(67));
+        color("sunny",      Normal("#3891c0"),              Normal(31));
+        color("yellow",     Normal("#877200"),              Normal(94));
+        color("gold",       Normal("#ad7a00"),              Normal(136));
+        color("dullgold",   Normal("#98794a"),              Normal(137));
+        color("darkgold",   Contrast("#f1e4ad", "#ebdd9f"), Normal(187));
+        color("mildred",    Normal("#e6a6a0"),              Normal(217));
+        color("red",        Normal("#cf3f48"),              Normal(167));
+        color("crimson",    Normal("#c2242e"),              Normal(124));
+        color("darkblue",   Normal("#dfe6ef"),              Normal(254));
+        color("whitepink",  Normal("#7c4a5b"),              Normal(95));
+        color("whitegreen", Normal("#5b6a12"),              Normal(58));
+        color("whiteblue",  Normal("#2d4e72"),              Normal(24));
+        color("whitered",   Normal("#8d392b"),              Normal(52));
+        color("inu",        Normal("#98744e"),              Normal(137));
+
+        Self {
+            variant: Variant::Morning,
+            colors: table,
+        }
+    }
+
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// Load a palette from a TOML document. Each color is defined in the `[colors]` table as
+    /// `name = { gui = "#rrggbb", cterm = 123 }`. `gui` and `cterm` can also be a table of high
+    /// and low contrast values like `{ high = "#132132", low = "#334152" }`. `cterm` can be omitted
+    /// and then it is derived from `gui`. The palette must define all colors which are defined in
+    /// `Palette::default`. Optional `background = "light"` at toplevel makes the palette for the
+    /// light variant.
+    pub fn from_toml(doc: &'a DocumentMut) -> Result<Self> {
+        let variant = match doc.get("background").map(|i| i.as_str()) {
+            None | Some(Some("dark")) => Variant::Night,
+            Some(Some("light")) => Variant::Morning,
+            Some(_) => bail!("'background' must be \"dark\" or \"light\""),
+        };
+
+        let Some(colors) = doc.get("colors").and_then(Item::as_table_like) else {
+            bail!("[colors] table is not found in the palette");
+        };
+
+        let mut table = HashMap::new();
+        for (name, item) in colors.iter() {
+            let Some(color) = item.as_table_like() else {
+                bail!("Color '{name}' must be a table with 'gui' and 'cterm' keys");
+            };
+            if let Some((key, _)) = color.iter().find(|(k, _)| *k != "gui" && *k != "cterm") {
+                bail!("Unknown key '{key}' in color '{name}'");
+            }
+
+            let gui = color
+                .get("gui")
+                .with_context(|| format!("'gui' is missing in color '{name}'"))?;
+            let gui = parse_color_code(gui, |v| v.as_str().filter(|s| is_hex_color(s)))
+                .with_context(|| {
+                    format!("'gui' of color '{name}' must be a \"#rrggbb\" string or a table of high and low contrast strings")
+                })?;
+
+            // When 'cterm' is omitted, the nearest xterm-256 colors are used
+            let cterm = match color.get("cterm") {
+                Some(cterm) => parse_color_code(cterm, |v| v.as_integer()?.try_into().ok())
+                    .with_context(|| {
+                        format!("'cterm' of color '{name}' must be an integer in 0..=255 or a table of high and low contrast integers")
+                    })?,
+                None => gui.map(|c| Rgb::from_hex(c).unwrap().nearest_xterm()),
+            };
+
+            table.insert(name, Color { gui, cterm });
+        }
+
+        let builtin = match variant {
+            Variant::Night => Palette::default(),
+            Variant::Morning => Palette::morning(),
+        };
+        let mut missing: Vec<_> = builtin
+            .keys()
+            .filter(|name| !table.contains_key(*name))
+            .copied()
+            .collect();
+        if !missing.is_empty() {
+            missing.sort_unstable();
+            bail!("Colors are missing in the palette: {}", missing.join(", "));
+        }
+
+        Ok(Self {
+            variant,
+            colors: table,
+        })
+    }
+
+    /// Find cterm colors whose perceptual distance from the nearest xterm-256 colors of their GUI
+    /// colors is larger than the threshold. Results are sorted by color names.
+    pub fn cterm_mismatches(&self, threshold: f64) -> Vec<CtermMismatch<'a>> {
+        let mut mismatches = vec![];
+        for (&name, color) in self.colors.iter() {
+            let pairs = match (&color.gui, &color.cterm) {
+                (ColorCode::Contrast(gh, gl), ColorCode::Contrast(ch, cl)) => {
+                    vec![(*gh, *ch), (*gl, *cl)]
+                }
+                (gui, ColorCode::Contrast(ch, cl)) => {
+                    vec![(*gui.normal(), *ch), (*gui.normal(), *cl)]
+                }
+                (gui, ColorCode::Normal(c)) => vec![(*gui.normal(), *c)],
+            };
+            for (gui, cterm) in pairs {
+                let nearest = Rgb::from_hex(gui).unwrap().nearest_xterm();
+                let distance = Rgb::from_xterm(cterm).distance(Rgb::from_xterm(nearest));
+                if distance > threshold {
+                    mismatches.push(CtermMismatch {
+                        name,
+                        gui,
+                        cterm,
+                        nearest,
+                        distance,
+                    });
+                }
+            }
+        }
+        mismatches.sort_by(|a, b| (a.name, a.gui).cmp(&(b.name, b.gui)));
+        mismatches
     }
 }
 
@@ -113,9 +393,8 @@ mod tests {
 
     #[test]
     fn test_hex_color_format() {
-        let palette = Palette::default();
         let re = Regex::new(r"^#[[:xdigit:]]{6}$").unwrap();
-        for (name, c) in palette.iter() {
+        for (name, c) in Palette::default().iter().chain(Palette::morning().iter()) {
             match c.gui {
                 ColorCode::Normal(c) => {
                     assert!(re.is_match(c), "'{c}' is invalid color code at '{name}'");
@@ -127,4 +406,165 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_variant_palettes() {
+        let (night, morning) = (Palette::default(), Palette::morning());
+        assert_eq!(night.variant(), Variant::Night);
+        assert_eq!(morning.variant(), Variant::Morning);
+        let mut night_names: Vec<_> = night.keys().collect();
+        let mut morning_names: Vec<_> = morning.keys().collect();
+        night_names.sort_unstable();
+        morning_names.sort_unstable();
+        assert_eq!(night_names, morning_names);
+    }
+
+    fn default_palette_toml() -> String {
+        fn code<T: Display>(c: &ColorCode<T>, quote: &str) -> String {
+            match c {
+                ColorCode::Normal(c) => format!("{quote}{c}{quote}"),
+                ColorCode::Contrast(h, l) => {
+                    format!("{{ high = {quote}{h}{quote}, low = {quote}{l}{quote} }}")
+                }
+            }
+        }
+
+        let mut toml = "[colors]\n".to_string();
+        for (name, c) in Palette::default().iter() {
+            let (gui, cterm) = (code(&c.gui, "\""), code(&c.cterm, ""));
+            toml.push_str(&format!("{name} = {{ gui = {gui}, cterm = {cterm} }}\n"));
+        }
+        toml
+    }
+
+    #[test]
+    fn test_load_palette_from_toml() {
+        let doc: DocumentMut = default_palette_toml().parse().unwrap();
+        let palette = Palette::from_toml(&doc).unwrap();
+        assert_eq!(palette.variant(), Variant::Night);
+        assert_eq!(palette.colors, Palette::default().colors);
+
+        let toml = format!("background = \"light\"\n{}", default_palette_toml());
+        let doc: DocumentMut = toml.parse().unwrap();
+        let palette = Palette::from_toml(&doc).unwrap();
+        assert_eq!(palette.variant(), Variant::Morning);
+
+        let mut toml = default_palette_toml();
+        toml.push_str("\n[colors.extra]\ngui = \"#012345\"\ncterm = { high = 1, low = 2 }\n");
+        toml.push_str("\n[colors.derived]\ngui = { high = \"#ffffff\", low = \"#b6955b\" }\n");
+        let doc: DocumentMut = toml.parse().unwrap();
+        let palette = Palette::from_toml(&doc).unwrap();
+        assert_eq!(
+            palette["extra"],
+            Color {
+                gui: ColorCode::Normal("#012345"),
+                cterm: ColorCode::Contrast(1, 2),
+            },
+        );
+        assert_eq!(palette["derived"].cterm, ColorCode::Contrast(231, 137));
+    }
+
+    #[test]
+    fn test_load_palette_from_json() {
+        let mut colors = serde_json::Map::new();
+        for (name, c) in Palette::morning().iter() {
+            let gui = match c.gui {
+                ColorCode::Normal(c) => serde_json::json!(c),
+                ColorCode::Contrast(h, l) => serde_json::json!({ "high": h, "low": l }),
+            };
+            colors.insert(name.to_string(), serde_json::json!({ "gui": gui }));
+        }
+        let json = serde_json::json!({ "background": "light", "colors": colors }).to_string();
+        let doc = toml_from_json(&json).unwrap();
+        let palette = Palette::from_toml(&doc).unwrap();
+        assert_eq!(palette.variant(), Variant::Morning);
+        for (name, c) in Palette::morning().iter() {
+            assert_eq!(palette[name].gui, c.gui, "{name}");
+        }
+
+        for (json, expected) in [
+            ("[]", "Palette must be a JSON object"),
+            (
+                "{\"colors\": {\"bg\": {\"cterm\": 1.5}}}",
+                "Invalid value at 'colors'",
+            ),
+            ("{\"colors\": null}", "Invalid value at 'colors'"),
+        ] {
+            let err = toml_from_json(json).unwrap_err().to_string();
+            assert_eq!(err, expected, "{json}");
+        }
+        assert!(toml_from_json("{").is_err());
+    }
+
+    #[test]
+    fn test_cterm_mismatches() {
+        let palette = Palette::default();
+        let mismatches = palette.cterm_mismatches(10.0);
+        let dullgold = mismatches.iter().find(|m| m.name == "dullgold").unwrap();
+        assert_eq!(
+            (dullgold.gui, dullgold.cterm, dullgold.nearest),
+            ("#b6955b", 221, 137)
+        );
+        assert!(dullgold.distance > 10.0, "{dullgold:?}");
+        assert!(
+            mismatches.iter().all(|m| m.distance > 10.0),
+            "{mismatches:?}"
+        );
+
+        let mut m = HashMap::new();
+        m.insert(
+            "exact",
+            Color {
+                gui: ColorCode::Normal("#af875f"),
+                cterm: ColorCode::Normal(137),
+            },
+        );
+        m.insert(
+            "contrast",
+            Color {
+                gui: ColorCode::Contrast("#ffffff", "#000000"),
+                cterm: ColorCode::Contrast(231, 231),
+            },
+        );
+        let palette = Palette::from(m);
+        let mismatches = palette.cterm_mismatches(10.0);
+        assert_eq!(mismatches.len(), 1, "{mismatches:?}");
+        assert_eq!(
+            (mismatches[0].name, mismatches[0].gui, mismatches[0].nearest),
+            ("contrast", "#000000", 16),
+        );
+        assert!(palette.cterm_mismatches(1000.0).is_empty());
+    }
+
+    #[test]
+    fn test_load_invalid_palette_from_toml() {
+        let default = default_palette_toml();
+        #[rustfmt::skip]
+        let testcases = [
+            ("[palette]\n",                                              "[colors] table is not found"),
+            ("background = \"dim\"\n[colors]\n",                          "'background' must be \"dark\" or \"light\""),
+            ("[colors]\nbg = \"#000000\"\n",                            "Color 'bg' must be a table"),
+            ("[colors]\nbg = { cterm = 0 }\n",                           "'gui' is missing in color 'bg'"),
+            ("[colors]\nbg = { gui = \"#000000\", cterm = 0, x = 1 }\n", "Unknown key 'x' in color 'bg'"),
+            ("[colors]\nbg = { gui = \"#00000\", cterm = 0 }\n",         "'gui' of color 'bg' must be"),
+            ("[colors]\nbg = { gui = \"#00000g\", cterm = 0 }\n",        "'gui' of color 'bg' must be"),
+            ("[colors]\nbg = { gui = \"#000000\", cterm = 256 }\n",      "'cterm' of color 'bg' must be"),
+            ("[colors]\nbg = { gui = \"#000000\", cterm = { high = 1 } }\n", "'cterm' of color 'bg' must be"),
+            ("[colors]\nbg = { gui = { high = \"#000000\", low = 1 }, cterm = 0 }\n", "'gui' of color 'bg' must be"),
+            ("[colors]\nbg = { gui = \"#000000\", cterm = 0 }\n",        "Colors are missing in the palette: bgemphasis, bglight,"),
+        ];
+        for (toml, expected) in testcases {
+            let doc: DocumentMut = toml.parse().unwrap();
+            let err = Palette::from_toml(&doc).unwrap_err().to_string();
+            assert!(
+                err.contains(expected),
+                "{err:?} does not contain {expected:?}"
+            );
+        }
+
+        let toml = default.replace("bgweaker = ", "bgweakest = ");
+        let doc: DocumentMut = toml.parse().unwrap();
+        let err = Palette::from_toml(&doc).unwrap_err().to_string();
+        assert_eq!(err, "Colors are missing in the palette: bgweaker");
+    }
 }
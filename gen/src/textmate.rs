@@ -0,0 +1,270 @@
+use crate::colorscheme::{Colorscheme, HiCommand};
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+// TextMate scopes and the highlight groups giving their colors
+#[rustfmt::skip]
+pub const TOKEN_SCOPES: &[(&[&str], &str)] = &[
+    (&["comment", "punctuation.definition.comment"],                   "Comment"),
+    (&["comment.block.documentation"],                                 "@comment.documentation"),
+    (&["string", "punctuation.definition.string"],                     "String"),
+    (&["string.regexp"],                                               "@string.regexp"),
+    (&["constant.character.escape"],                                   "@string.escape"),
+    (&["constant.character"],                                          "Character"),
+    (&["constant.numeric"],                                            "Number"),
+    (&["constant.language.boolean"],                                   "Boolean"),
+    (&["constant", "variable.other.constant"],                         "Constant"),
+    (&["constant.language"],                                           "@constant.builtin"),
+    (&["variable", "meta.definition.variable.name"],                   "@variable"),
+    (&["variable.language"],                                           "@variable.builtin"),
+    (&["variable.parameter"],                                          "@variable.parameter"),
+    (&["variable.other.property", "variable.other.object.property"],   "@property"),
+    (&["keyword"],                                                     "Keyword"),
+    (&["keyword.control"],                                             "Statement"),
+    (&["keyword.control.conditional"],                                 "Conditional"),
+    (&["keyword.control.loop"],                                        "@keyword.repeat"),
+    (&["keyword.control.import", "keyword.control.directive"],         "PreProc"),
+    (&["keyword.operator"],                                            "Operator"),
+    (&["storage.type"],                                                "@keyword.type"),
+    (&["storage.modifier"],                                            "StorageClass"),
+    (&["entity.name.function", "support.function"],                    "Function"),
+    (&["entity.name.function.macro"],                                  "@function.macro"),
+    (&["entity.name.type", "entity.name.class", "support.type", "support.class"], "Type"),
+    (&["entity.name.namespace"],                                       "@module"),
+    (&["entity.name.tag"],                                             "Tag"),
+    (&["entity.other.attribute-name"],                                 "@tag.attribute"),
+    (&["meta.preprocessor"],                                           "PreProc"),
+    (&["markup.heading"],                                              "Title"),
+    (&["markup.bold"],                                                 "@markup.strong"),
+    (&["markup.italic"],                                               "@markup.italic"),
+    (&["markup.strikethrough"],                                        "@markup.strikethrough"),
+    (&["markup.underline.link"],                                       "Underlined"),
+    (&["markup.inline.raw", "markup.raw"],                             "@markup.raw"),
+    (&["markup.quote"],                                                "@markup.quote"),
+    (&["markup.inserted"],                                             "diffAdded"),
+    (&["markup.deleted"],                                              "diffRemoved"),
+    (&["invalid"],                                                     "Error"),
+    (&["invalid.deprecated"],                                          "@lsp.mod.deprecated"),
+];
+
+/// Pairs of TextMate scopes and their highlights. They are shared with VS Code token colors
+pub fn token_rules<'a>(
+    colorscheme: &Colorscheme<'a>,
+) -> Vec<(&'static [&'static str], &'a HiCommand)> {
+    TOKEN_SCOPES
+        .iter()
+        .map(|&(scopes, name)| (scopes, colorscheme.resolve(name).unwrap()))
+        .collect()
+}
+
+// TextMate theme (.tmTheme) loaded by Sublime Text, bat, git-delta and so on. Global settings come
+// from the UI highlights and scope rules come from the syntax highlights of `Colorscheme`.
+#[derive(Debug)]
+pub struct TextMateTheme<'a> {
+    palette: &'a Palette<'a>,
+    settings: Vec<(&'static str, &'a str)>,
+    rules: Vec<(&'static [&'static str], &'a HiCommand)>,
+}
+
+impl<'a> TextMateTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let colorscheme = Colorscheme::new(palette);
+        let hl = |name| colorscheme.resolve(name).unwrap();
+        let fg = |name| hl(name).fg.unwrap();
+        let bg = |name| hl(name).bg.unwrap();
+
+        let settings = vec![
+            ("background", bg("Normal")),
+            ("foreground", fg("Normal")),
+            ("caret", bg("Cursor")),
+            ("lineHighlight", bg("CursorLine")),
+            ("selection", bg("Visual")),
+            ("findHighlight", bg("Search")),
+            ("invisibles", fg("SpecialKey")),
+            ("gutter", bg("LineNr")),
+            ("gutterForeground", fg("LineNr")),
+        ];
+
+        Self {
+            palette,
+            settings,
+            rules: token_rules(&colorscheme),
+        }
+    }
+
+    fn color(&self, name: &str) -> &'_ str {
+        self.palette[name].gui.normal()
+    }
+
+    fn write_header(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(
+            w,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<!--
+  TextMate theme for {name} colorscheme
+
+  Author: rhysd <lin90162@yahoo.co.jp>
+  License: MIT
+    Copyright (c) 2016 rhysd
+
+  PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+  Generated by script vim-color-spring-night/gen/{source}
+-->
+<plist version="1.0">
+<dict>"#,
+            name = self.palette.variant().name(),
+            source = file!(),
+        )
+    }
+
+    fn write_string(&self, w: &mut dyn Write, indent: &str, key: &str, val: &str) -> Result<()> {
+        writeln!(w, "{indent}<key>{key}</key>")?;
+        writeln!(w, "{indent}<string>{val}</string>")
+    }
+
+    fn write_rule(&self, w: &mut dyn Write, scopes: &[&str], cmd: &HiCommand) -> Result<()> {
+        writeln!(w, "\t\t<dict>")?;
+        self.write_string(w, "\t\t\t", "name", cmd.name)?;
+        self.write_string(w, "\t\t\t", "scope", &scopes.join(", "))?;
+        writeln!(w, "\t\t\t<key>settings</key>")?;
+        writeln!(w, "\t\t\t<dict>")?;
+        if let Some(fg) = cmd.fg.filter(|&c| c != "NONE") {
+            self.write_string(w, "\t\t\t\t", "foreground", self.color(fg))?;
+        }
+        let styles = cmd.attr.font_styles();
+        if !styles.is_empty() {
+            self.write_string(w, "\t\t\t\t", "fontStyle", &styles.join(" "))?;
+        }
+        writeln!(w, "\t\t\t</dict>")?;
+        writeln!(w, "\t\t</dict>")
+    }
+}
+
+impl ThemeWriter for TextMateTheme<'_> {
+    fn target(&self) -> &'static str {
+        "textmate"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&["textmate", &format!("{}.tmTheme", variant.name())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        self.write_header(w)?;
+        self.write_string(w, "\t", "name", self.palette.variant().name())?;
+        writeln!(w, "\t<key>settings</key>")?;
+        writeln!(w, "\t<array>")?;
+        writeln!(w, "\t\t<dict>")?;
+        writeln!(w, "\t\t\t<key>settings</key>")?;
+        writeln!(w, "\t\t\t<dict>")?;
+        for (key, name) in &self.settings {
+            self.write_string(w, "\t\t\t\t", key, self.color(name))?;
+        }
+        writeln!(w, "\t\t\t</dict>")?;
+        writeln!(w, "\t\t</dict>")?;
+        for (scopes, cmd) in &self.rules {
+            self.write_rule(w, scopes, cmd)?;
+        }
+        writeln!(w, "\t</array>")?;
+        writeln!(w, "</dict>")?;
+        writeln!(w, "</plist>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use std::collections::HashMap;
+    use std::str;
+
+    // Parse the <dict> element into a map from keys to values
+    fn parse_dict<'a, 'i>(
+        dict: roxmltree::Node<'a, 'i>,
+    ) -> HashMap<&'a str, roxmltree::Node<'a, 'i>> {
+        assert_eq!(dict.tag_name().name(), "dict");
+        let mut entries = HashMap::new();
+        let mut children = dict.children().filter(|n| n.is_element());
+        while let Some(key) = children.next() {
+            assert_eq!(key.tag_name().name(), "key");
+            let value = children.next().unwrap();
+            assert!(entries.insert(key.text().unwrap(), value).is_none());
+        }
+        entries
+    }
+
+    #[test]
+    fn test_write_plist() {
+        let hex_color = Regex::new(r"^#[[:xdigit:]]{6}$").unwrap();
+
+        for palette in [Palette::default(), Palette::morning()] {
+            let w = TextMateTheme::new(&palette);
+            let mut out = vec![];
+            w.write_to(&mut out).unwrap();
+            let src = str::from_utf8(&out).unwrap();
+
+            let opts = roxmltree::ParsingOptions {
+                allow_dtd: true,
+                ..Default::default()
+            };
+            let doc = roxmltree::Document::parse_with_options(src, opts).unwrap();
+            let root = doc.root_element();
+            assert_eq!(root.tag_name().name(), "plist");
+            let theme = parse_dict(root.children().find(|n| n.is_element()).unwrap());
+            assert_eq!(theme["name"].text(), Some(palette.variant().name()));
+
+            let mut items = theme["settings"].children().filter(|n| n.is_element());
+            let globals = parse_dict(items.next().unwrap());
+            let globals = parse_dict(globals["settings"]);
+            for key in [
+                "background",
+                "foreground",
+                "caret",
+                "lineHighlight",
+                "selection",
+                "gutter",
+                "gutterForeground",
+            ] {
+                let color = globals.get(key).expect(key).text().unwrap();
+                assert!(hex_color.is_match(color), "{key}: {color:?}");
+            }
+            assert_eq!(
+                globals["background"].text(),
+                Some(*palette["bg"].gui.normal())
+            );
+
+            let mut rules = HashMap::new();
+            for item in items {
+                let rule = parse_dict(item);
+                let settings = parse_dict(rule["settings"]);
+                assert!(!settings.is_empty(), "{rule:?}");
+                if let Some(fg) = settings.get("foreground") {
+                    let fg = fg.text().unwrap();
+                    assert!(hex_color.is_match(fg), "{fg:?}");
+                }
+                let scope = rule["scope"].text().unwrap();
+                for s in scope.split(", ") {
+                    let fg = settings.get("foreground").and_then(|n| n.text());
+                    assert!(rules.insert(s, fg).is_none(), "{s}");
+                }
+            }
+            assert_eq!(
+                rules.len(),
+                TOKEN_SCOPES.iter().map(|(s, _)| s.len()).sum::<usize>()
+            );
+
+            for (scope, name) in [
+                ("markup.inserted", "green"),
+                ("markup.deleted", "red"),
+                ("string", "green"),
+                ("comment", "weakfg"),
+            ] {
+                assert_eq!(rules[scope], Some(*palette[name].gui.normal()), "{scope}");
+            }
+        }
+    }
+}
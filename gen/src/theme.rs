@@ -0,0 +1,215 @@
+use crate::airline::AirlineTheme;
+use crate::alacritty::AlacrittyTheme;
+use crate::colorscheme::Colorscheme;
+use crate::foot::FootTheme;
+use crate::ghostty::GhosttyTheme;
+use crate::helix::HelixTheme;
+use crate::iterm2::{ColorSpace, ITerm2Theme};
+use crate::kitty::KittyTheme;
+use crate::lightline::LightlineTheme;
+use crate::lua::LuaColorscheme;
+use crate::palette::Palette;
+use crate::textmate::TextMateTheme;
+use crate::tmux::TmuxTheme;
+use crate::vscode::VsCodeTheme;
+use crate::wezterm::WezTermTheme;
+use crate::windows_terminal::WindowsTerminalTheme;
+use crate::xresources::{VtRgbTheme, XresourcesTheme};
+use crate::zed::ZedTheme;
+use anyhow::bail;
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+/// Generator of one file for one palette. Implement this trait and add the writer to `writers` to
+/// add a new output format.
+pub trait ThemeWriter {
+    /// Name of the output format such as "vim" or "alacritty"
+    fn target(&self) -> &'static str;
+    /// Path of the generated file relative to the repository root
+    fn path(&self) -> PathBuf;
+    fn write_to(&self, w: &mut dyn Write) -> Result<()>;
+}
+
+/// Joins path components into a relative path
+pub fn path(entries: &[&str]) -> PathBuf {
+    entries.iter().collect()
+}
+
+/// All targets generated for the palette
+pub fn writers<'a>(
+    palette: &'a Palette,
+    color_space: ColorSpace,
+) -> Vec<Box<dyn ThemeWriter + 'a>> {
+    vec![
+        Box::new(Colorscheme::new(palette)),
+        Box::new(LuaColorscheme::new(palette)),
+        Box::new(AirlineTheme::new(palette)),
+        Box::new(LightlineTheme::new(palette)),
+        Box::new(AlacrittyTheme::new(palette)),
+        Box::new(ITerm2Theme::new(palette, color_space)),
+        Box::new(KittyTheme::new(palette)),
+        Box::new(WezTermTheme::new(palette)),
+        Box::new(WindowsTerminalTheme::new(palette)),
+        Box::new(GhosttyTheme::new(palette)),
+        Box::new(FootTheme::new(palette)),
+        Box::new(XresourcesTheme::new(palette)),
+        Box::new(VtRgbTheme::new(palette)),
+        Box::new(TmuxTheme::new(palette)),
+        Box::new(VsCodeTheme::new(palette)),
+        Box::new(TextMateTheme::new(palette)),
+        Box::new(HelixTheme::new(palette)),
+        Box::new(ZedTheme::new(palette)),
+    ]
+}
+
+/// Writers of the targets selected by the names. A name is a target name such as "vim" which selects
+/// the target for all palettes, or a name qualified with the palette variant such as
+/// "vim:spring-morning". All targets are selected when no name is given.
+pub fn select<'a>(
+    palettes: &'a [Palette<'a>],
+    color_space: ColorSpace,
+    names: &[String],
+) -> anyhow::Result<Vec<Box<dyn ThemeWriter + 'a>>> {
+    let mut selected = vec![];
+    let mut matched = vec![false; names.len()];
+    for palette in palettes {
+        let variant = palette.variant().name();
+        for writer in writers(palette, color_space) {
+            let target = writer.target();
+            let mut is_selected = names.is_empty();
+            for (name, matched) in names.iter().zip(matched.iter_mut()) {
+                let is_match = match name.split_once(':') {
+                    Some((t, v)) => t == target && v == variant,
+                    None => name == target,
+                };
+                if is_match {
+                    *matched = true;
+                    is_selected = true;
+                }
+            }
+            if is_selected {
+                selected.push(writer);
+            }
+        }
+    }
+
+    let unknown: Vec<_> = names
+        .iter()
+        .zip(matched)
+        .filter_map(|(name, matched)| (!matched).then_some(name.as_str()))
+        .collect();
+    if !unknown.is_empty() {
+        bail!("Unknown targets: {}", unknown.join(", "));
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::palette::ColorCode;
+    use regex::Regex;
+    use std::collections::HashSet;
+
+    /// Check that every color emitted in the rendered theme is `#rrggbb` color defined in the
+    /// palette and return the number of emitted colors
+    pub(crate) fn assert_palette_colors(palette: &Palette, rendered: &str) -> usize {
+        let defined: HashSet<_> = palette
+            .values()
+            .flat_map(|c| match c.gui {
+                ColorCode::Normal(c) => [c, c],
+                ColorCode::Contrast(hi, lo) => [hi, lo],
+            })
+            .collect();
+        let re_color = Regex::new(r"#[[:xdigit:]]+\b").unwrap();
+        let mut count = 0;
+        for m in re_color.find_iter(rendered) {
+            let color = m.as_str();
+            assert_eq!(color.len(), 7, "{color:?} is not #rrggbb");
+            assert!(defined.contains(color), "{color:?} is not in the palette");
+            count += 1;
+        }
+        assert!(count > 0, "no color is emitted");
+        count
+    }
+
+    #[test]
+    fn test_writers() {
+        let palettes = [Palette::default(), Palette::morning()];
+        let mut paths = HashSet::new();
+        for palette in &palettes {
+            let writers = writers(palette, ColorSpace::default());
+            let targets: HashSet<_> = writers.iter().map(|w| w.target()).collect();
+            assert_eq!(targets.len(), writers.len(), "{targets:?}");
+
+            for w in &writers {
+                let path = w.path();
+                assert!(path.is_relative(), "{path:?}");
+                assert!(paths.insert(path.clone()), "duplicate path {path:?}");
+
+                let mut out = vec![];
+                w.write_to(&mut out).unwrap();
+                assert!(!out.is_empty(), "{path:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_select() {
+        let palettes = [Palette::default(), Palette::morning()];
+        let select = |names: &[&str]| -> Vec<PathBuf> {
+            let names: Vec<_> = names.iter().map(|n| n.to_string()).collect();
+            select(&palettes, ColorSpace::default(), &names)
+                .unwrap()
+                .iter()
+                .map(|w| w.path())
+                .collect()
+        };
+
+        let all = select(&[]);
+        assert_eq!(
+            all.len(),
+            writers(&palettes[0], ColorSpace::default()).len() * 2
+        );
+
+        assert_eq!(
+            select(&["alacritty"]),
+            [
+                path(&["alacritty", "spring_night.toml"]),
+                path(&["alacritty", "spring_morning.toml"]),
+            ],
+        );
+        assert_eq!(
+            select(&["vim:spring-morning"]),
+            [path(&["colors", "spring-morning.vim"])],
+        );
+        assert_eq!(
+            select(&["lua:spring-night", "vim", "lua"]),
+            [
+                path(&["colors", "spring-night.vim"]),
+                path(&["colors", "spring-night.lua"]),
+                path(&["colors", "spring-morning.vim"]),
+                path(&["colors", "spring-morning.lua"]),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_select_unknown_target() {
+        let palettes = [Palette::default(), Palette::morning()];
+        for (names, want) in [
+            (&["foo"][..], "Unknown targets: foo"),
+            (
+                &["vim", "vim:spring-noon", "Vim"],
+                "Unknown targets: vim:spring-noon, Vim",
+            ),
+        ] {
+            let names: Vec<_> = names.iter().map(|n| n.to_string()).collect();
+            let Err(err) = select(&palettes, ColorSpace::default(), &names) else {
+                panic!("error did not occur for {names:?}");
+            };
+            assert_eq!(err.to_string(), want);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::airline::AirlineTheme;
+use crate::alacritty::AlacrittyTheme;
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+type Color<'a> = (&'a str, &'a str); // Pair of foreground/background colors
+
+// tmux theme. Status line colors are shared with normal mode of `AirlineTheme` so that tmux status
+// line looks the same as Vim's status line. Selection and search colors are shared with
+// `AlacrittyTheme`.
+#[derive(Debug)]
+pub struct TmuxTheme<'a> {
+    palette: &'a Palette<'a>,
+    styles: Vec<(&'static str, Color<'a>)>,
+}
+
+impl<'a> TmuxTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let [label, info, main] = AirlineTheme::new(palette).sections("normal");
+        let alacritty = AlacrittyTheme::new(palette);
+        Self {
+            palette,
+            styles: vec![
+                ("status-style", main),
+                ("window-status-current-style", label),
+                ("pane-border-style", (info.1, alacritty.background())),
+                (
+                    "pane-active-border-style",
+                    (label.1, alacritty.background()),
+                ),
+                ("message-style", info),
+                ("mode-style", alacritty.selection()),
+                ("copy-mode-match-style", alacritty.search()),
+            ],
+        }
+    }
+}
+
+impl ThemeWriter for TmuxTheme<'_> {
+    fn target(&self) -> &'static str {
+        "tmux"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&["tmux", &format!("{}.conf", variant.ident())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(
+            w,
+            r#"# tmux theme for {name} colorscheme
+#
+# Author: rhysd <lin90162@yahoo.co.jp>
+# License: MIT
+#   Copyright (c) 2016 rhysd
+#
+# PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+# Generated by script vim-color-spring-night/gen/{source}
+"#,
+            name = self.palette.variant().name(),
+            source = file!(),
+        )?;
+        for (option, (fg, bg)) in &self.styles {
+            writeln!(
+                w,
+                "set -g {option} 'fg={},bg={}'",
+                self.palette[fg].gui.normal(),
+                self.palette[bg].gui.normal(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::tests::assert_palette_colors;
+    use regex::Regex;
+    use std::collections::HashMap;
+    use std::str;
+
+    #[test]
+    fn test_write_theme() {
+        let re_option = Regex::new(r"^set -g ([a-z-]+) 'fg=(#\w+),bg=(#\w+)'$").unwrap();
+
+        for palette in [Palette::default(), Palette::morning()] {
+            let w = TmuxTheme::new(&palette);
+            let mut out = vec![];
+            w.write_to(&mut out).unwrap();
+            let rendered = str::from_utf8(&out).unwrap();
+            assert_palette_colors(&palette, rendered);
+
+            let mut styles = HashMap::new();
+            for line in rendered.lines() {
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                let found = re_option.captures(line).expect(line);
+                let style = (found[2].to_string(), found[3].to_string());
+                assert!(
+                    styles.insert(found[1].to_string(), style).is_none(),
+                    "{line}"
+                );
+            }
+
+            for option in [
+                "status-style",
+                "window-status-current-style",
+                "pane-border-style",
+                "pane-active-border-style",
+                "message-style",
+                "mode-style",
+                "copy-mode-match-style",
+            ] {
+                assert!(
+                    styles.contains_key(option),
+                    "{option} is missing: {styles:?}"
+                );
+            }
+            assert_eq!(styles.len(), 7, "{styles:?}");
+
+            // Status line is the same as the main section of airline in normal mode
+            let [_, _, (fg, bg)] = AirlineTheme::new(&palette).sections("normal");
+            let status = (
+                palette[fg].gui.normal().to_string(),
+                palette[bg].gui.normal().to_string(),
+            );
+            assert_eq!(styles["status-style"], status);
+        }
+    }
+}
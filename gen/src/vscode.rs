@@ -0,0 +1,325 @@
+use crate::airline::AirlineTheme;
+use crate::colorscheme::{Colorscheme, HiCommand};
+use crate::palette::Palette;
+use crate::textmate;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+// Keys of ANSI 16 colors of the integrated terminal
+const ANSI_KEYS: [&str; 16] = [
+    "terminal.ansiBlack",
+    "terminal.ansiRed",
+    "terminal.ansiGreen",
+    "terminal.ansiYellow",
+    "terminal.ansiBlue",
+    "terminal.ansiMagenta",
+    "terminal.ansiCyan",
+    "terminal.ansiWhite",
+    "terminal.ansiBrightBlack",
+    "terminal.ansiBrightRed",
+    "terminal.ansiBrightGreen",
+    "terminal.ansiBrightYellow",
+    "terminal.ansiBrightBlue",
+    "terminal.ansiBrightMagenta",
+    "terminal.ansiBrightCyan",
+    "terminal.ansiBrightWhite",
+];
+
+// Selectors of semantic tokens and the highlight groups giving their colors. They follow Neovim's
+// LSP semantic token highlights.
+#[rustfmt::skip]
+const SEMANTIC_TOKENS: &[(&str, &str)] = &[
+    ("namespace",               "@lsp.type.namespace"),
+    ("type",                    "@lsp.type.type"),
+    ("class",                   "@lsp.type.class"),
+    ("enum",                    "@lsp.type.enum"),
+    ("interface",               "@lsp.type.interface"),
+    ("struct",                  "@lsp.type.struct"),
+    ("typeParameter",           "@lsp.type.typeParameter"),
+    ("parameter",               "@lsp.type.parameter"),
+    ("variable",                "@lsp.type.variable"),
+    ("property",                "@lsp.type.property"),
+    ("enumMember",              "@lsp.type.enumMember"),
+    ("decorator",               "@lsp.type.decorator"),
+    ("function",                "@lsp.type.function"),
+    ("method",                  "@lsp.type.method"),
+    ("macro",                   "@lsp.type.macro"),
+    ("keyword",                 "@lsp.type.keyword"),
+    ("comment",                 "@lsp.type.comment"),
+    ("string",                  "@lsp.type.string"),
+    ("number",                  "@lsp.type.number"),
+    ("operator",                "@lsp.type.operator"),
+    ("function.defaultLibrary", "@lsp.typemod.function.defaultLibrary"),
+    ("variable.defaultLibrary", "@lsp.typemod.variable.defaultLibrary"),
+    ("*.deprecated",            "@lsp.mod.deprecated"),
+];
+
+// VS Code color theme. Workbench colors follow the highlights of UI and the airline theme, and token
+// colors follow the highlights of syntax and LSP semantic tokens.
+#[derive(Debug)]
+pub struct VsCodeTheme<'a> {
+    palette: &'a Palette<'a>,
+    colors: Vec<(&'static str, &'a str)>,
+    tokens: Vec<(&'static [&'static str], &'a HiCommand)>,
+    semantic_tokens: Vec<(&'static str, &'a HiCommand)>,
+}
+
+impl<'a> VsCodeTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let colorscheme = Colorscheme::new(palette);
+        let hl = |name| colorscheme.resolve(name).unwrap();
+        let fg = |name| hl(name).fg.unwrap();
+        let bg = |name| hl(name).bg.unwrap();
+        let [status_label, _, status_main] = AirlineTheme::new(palette).sections("normal");
+
+        let mut colors = vec![
+            ("foreground", fg("Normal")),
+            ("focusBorder", fg("FloatBorder")),
+            ("editor.background", bg("Normal")),
+            ("editor.foreground", fg("Normal")),
+            ("editor.lineHighlightBackground", bg("CursorLine")),
+            ("editor.selectionBackground", bg("Visual")),
+            ("editor.findMatchBackground", bg("IncSearch")),
+            ("editor.findMatchHighlightBackground", bg("Search")),
+            ("editorCursor.foreground", bg("Cursor")),
+            ("editorCursor.background", fg("Cursor")),
+            ("editorLineNumber.foreground", fg("LineNr")),
+            ("editorLineNumber.activeForeground", fg("CursorLineNr")),
+            ("editorGutter.background", bg("SignColumn")),
+            ("editorGutter.addedBackground", fg("SignifySignAdd")),
+            ("editorGutter.modifiedBackground", fg("SignifySignChange")),
+            ("editorGutter.deletedBackground", fg("SignifySignDelete")),
+            ("editorWhitespace.foreground", fg("SpecialKey")),
+            ("editorRuler.foreground", bg("ColorColumn")),
+            ("editorBracketMatch.background", bg("MatchParen")),
+            ("editorError.foreground", fg("DiagnosticError")),
+            ("editorWarning.foreground", fg("DiagnosticWarn")),
+            ("editorInfo.foreground", fg("DiagnosticInfo")),
+            ("editorHint.foreground", fg("DiagnosticHint")),
+            ("editorWidget.background", bg("NormalFloat")),
+            ("editorWidget.border", fg("FloatBorder")),
+            ("editorSuggestWidget.background", bg("Pmenu")),
+            ("editorSuggestWidget.foreground", fg("Pmenu")),
+            ("editorSuggestWidget.selectedBackground", bg("PmenuSel")),
+            ("editorSuggestWidget.selectedForeground", fg("PmenuSel")),
+            ("editorGroupHeader.tabsBackground", fg("TabLineFill")),
+            ("editorGroup.border", fg("VertSplit")),
+            ("tab.activeBackground", bg("TabLineSel")),
+            ("tab.activeForeground", fg("TabLineSel")),
+            ("tab.inactiveBackground", bg("TabLine")),
+            ("tab.inactiveForeground", fg("TabLine")),
+            ("tab.border", fg("VertSplit")),
+            ("statusBar.background", status_main.1),
+            ("statusBar.foreground", status_main.0),
+            ("statusBarItem.remoteBackground", status_label.1),
+            ("statusBarItem.remoteForeground", status_label.0),
+            ("activityBar.background", bg("NormalFloat")),
+            ("activityBar.foreground", fg("NormalFloat")),
+            ("sideBar.background", bg("NormalFloat")),
+            ("sideBar.foreground", fg("NormalFloat")),
+            ("titleBar.activeBackground", bg("NormalFloat")),
+            ("titleBar.activeForeground", fg("NormalFloat")),
+            ("panel.background", bg("Normal")),
+            ("panel.border", fg("VertSplit")),
+            ("list.activeSelectionBackground", bg("PmenuSel")),
+            ("list.activeSelectionForeground", fg("PmenuSel")),
+            ("list.hoverBackground", bg("CursorLine")),
+        ];
+
+        // Terminal is the same as `:terminal` in Vim
+        let term_colors = colorscheme.term_colors();
+        colors.push(("terminal.background", term_colors[0]));
+        colors.push(("terminal.foreground", term_colors[7]));
+        colors.push(("terminalCursor.foreground", term_colors[7]));
+        colors.extend(ANSI_KEYS.into_iter().zip(*term_colors));
+
+        let tokens = textmate::token_rules(&colorscheme);
+        let semantic_tokens = SEMANTIC_TOKENS
+            .iter()
+            .map(|&(selector, name)| (selector, hl(name)))
+            .collect();
+
+        Self {
+            palette,
+            colors,
+            tokens,
+            semantic_tokens,
+        }
+    }
+
+    fn color(&self, name: &str) -> &'_ str {
+        self.palette[name].gui.normal()
+    }
+
+    // Foreground color of the highlight. `None` means the highlight does not change the color
+    fn foreground(&self, cmd: &HiCommand) -> Option<&'_ str> {
+        cmd.fg.filter(|&c| c != "NONE").map(|c| self.color(c))
+    }
+
+    fn write_colors(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(w, "  \"colors\": {{")?;
+        for (i, (key, name)) in self.colors.iter().enumerate() {
+            let comma = if i + 1 < self.colors.len() { "," } else { "" };
+            writeln!(w, "    \"{key}\": \"{}\"{comma}", self.color(name))?;
+        }
+        writeln!(w, "  }},")
+    }
+
+    fn write_token_colors(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(w, "  \"tokenColors\": [")?;
+        for (i, (scopes, cmd)) in self.tokens.iter().enumerate() {
+            let mut settings = vec![];
+            if let Some(fg) = self.foreground(cmd) {
+                settings.push(format!("\"foreground\": \"{fg}\""));
+            }
+            let styles = cmd.attr.font_styles();
+            if !styles.is_empty() {
+                settings.push(format!("\"fontStyle\": \"{}\"", styles.join(" ")));
+            }
+            let scopes: Vec<_> = scopes.iter().map(|s| format!("\"{s}\"")).collect();
+            let comma = if i + 1 < self.tokens.len() { "," } else { "" };
+            writeln!(w, "    {{")?;
+            writeln!(w, "      \"name\": \"{}\",", cmd.name)?;
+            writeln!(w, "      \"scope\": [{}],", scopes.join(", "))?;
+            writeln!(w, "      \"settings\": {{ {} }}", settings.join(", "))?;
+            writeln!(w, "    }}{comma}")?;
+        }
+        writeln!(w, "  ],")
+    }
+
+    fn write_semantic_token_colors(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(w, "  \"semanticTokenColors\": {{")?;
+        for (i, (selector, cmd)) in self.semantic_tokens.iter().enumerate() {
+            let mut style = vec![];
+            if let Some(fg) = self.foreground(cmd) {
+                style.push(format!("\"foreground\": \"{fg}\""));
+            }
+            for s in cmd.attr.font_styles() {
+                style.push(format!("\"{s}\": true"));
+            }
+            let comma = if i + 1 < self.semantic_tokens.len() {
+                ","
+            } else {
+                ""
+            };
+            writeln!(w, "    \"{selector}\": {{ {} }}{comma}", style.join(", "))?;
+        }
+        writeln!(w, "  }}")
+    }
+}
+
+impl ThemeWriter for VsCodeTheme<'_> {
+    fn target(&self) -> &'static str {
+        "vscode"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&["themes", &format!("{}-color-theme.json", variant.name())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        let variant = self.palette.variant();
+        writeln!(w, "{{")?;
+        writeln!(w, "  \"$schema\": \"vscode://schemas/color-theme\",")?;
+        writeln!(w, "  \"name\": \"{}\",", variant.name())?;
+        writeln!(w, "  \"type\": \"{}\",", variant.background())?;
+        writeln!(w, "  \"semanticHighlighting\": true,")?;
+        self.write_colors(w)?;
+        self.write_token_colors(w)?;
+        self.write_semantic_token_colors(w)?;
+        writeln!(w, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use serde_json::Value;
+
+    fn assert_theme(palette: &Palette) {
+        let w = VsCodeTheme::new(palette);
+        let mut out = vec![];
+        w.write_to(&mut out).unwrap();
+        let theme: Value = serde_json::from_slice(&out).unwrap();
+
+        let variant = palette.variant();
+        assert_eq!(theme["name"], variant.name());
+        assert_eq!(theme["type"], variant.background());
+
+        let hex_color = Regex::new(r"^#[[:xdigit:]]{6}$").unwrap();
+        let assert_color = |key: &str, v: &Value| {
+            let c = v.as_str().expect(key);
+            assert!(hex_color.is_match(c), "{key}: {c:?}");
+        };
+
+        let colors = theme["colors"].as_object().unwrap();
+        for key in [
+            "editor.background",
+            "editor.foreground",
+            "editor.lineHighlightBackground",
+            "editor.selectionBackground",
+            "editorGutter.background",
+            "statusBar.background",
+            "statusBar.foreground",
+            "tab.activeBackground",
+            "tab.inactiveBackground",
+            "terminal.ansiBlack",
+            "terminal.ansiBrightWhite",
+        ] {
+            assert!(colors.contains_key(key), "{key} is missing");
+        }
+        for (key, color) in colors {
+            assert_color(key, color);
+        }
+        assert_eq!(colors["editor.background"], *palette["bg"].gui.normal());
+        let ansi_keys = colors.keys().filter(|k| k.starts_with("terminal.ansi"));
+        assert_eq!(ansi_keys.count(), 16);
+
+        let tokens = theme["tokenColors"].as_array().unwrap();
+        assert_eq!(tokens.len(), textmate::TOKEN_SCOPES.len());
+        for token in tokens {
+            let scopes = token["scope"].as_array().expect("scope");
+            assert!(!scopes.is_empty(), "{token}");
+            let settings = token["settings"].as_object().expect("settings");
+            assert!(!settings.is_empty(), "{token}");
+            if let Some(fg) = settings.get("foreground") {
+                assert_color("foreground", fg);
+            }
+            if let Some(style) = settings.get("fontStyle") {
+                let style = style.as_str().unwrap();
+                for s in style.split(' ') {
+                    assert!(
+                        ["bold", "italic", "underline", "strikethrough"].contains(&s),
+                        "{style:?}"
+                    );
+                }
+            }
+        }
+        let comment = tokens.iter().find(|t| t["scope"][0] == "comment").unwrap();
+        assert_eq!(
+            comment["settings"]["foreground"],
+            *palette["weakfg"].gui.normal()
+        );
+
+        let semantic = theme["semanticTokenColors"].as_object().unwrap();
+        assert_eq!(semantic.len(), SEMANTIC_TOKENS.len());
+        assert_eq!(semantic["*.deprecated"]["strikethrough"], true);
+        assert_eq!(
+            semantic["enum"]["foreground"],
+            *palette["gold"].gui.normal()
+        );
+    }
+
+    #[test]
+    fn test_default_theme() {
+        assert_theme(&Palette::default());
+    }
+
+    #[test]
+    fn test_morning_theme() {
+        assert_theme(&Palette::morning());
+    }
+}
@@ -0,0 +1,272 @@
+use crate::alacritty::AlacrittyTheme;
+use crate::colorscheme::Colorscheme;
+use crate::contrast::ColorPair;
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+type Color<'a> = (&'a str, &'a str); // Pair of foreground/background colors
+
+// WezTerm color scheme. ANSI colors and selection colors are shared with `AlacrittyTheme`. Tab bar,
+// split, scrollbar and IME composition cursor follow TabLine, TabLineSel, TabLineFill, VertSplit,
+// PmenuThumb and IncSearch highlights of `Colorscheme`
+#[derive(Debug)]
+pub struct WezTermTheme<'a> {
+    palette: &'a Palette<'a>,
+    ansi: [&'a str; 8],
+    brights: [&'a str; 8],
+    primary: Color<'a>,
+    cursor: Color<'a>,
+    cursor_border: &'a str,
+    selection: Color<'a>,
+    scrollbar_thumb: &'a str,
+    split: &'a str,
+    compose_cursor: &'a str,
+    tab_bar: &'a str,
+    tabs: [(&'a str, Color<'a>); 5],
+}
+
+impl<'a> WezTermTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let alacritty = AlacrittyTheme::new(palette);
+        let (fg, bg) = (alacritty.foreground(), alacritty.background());
+        let colorscheme = Colorscheme::new(palette);
+        let hl = |name| colorscheme.resolve(name).unwrap();
+        let colors = |name| (hl(name).fg.unwrap(), hl(name).bg.unwrap());
+        let (active_tab, inactive_tab) = (colors("TabLineSel"), colors("TabLine"));
+        // Hovered tabs are emphasized with the normal foreground color
+        let hover_tab = (fg, inactive_tab.1);
+        Self {
+            palette,
+            ansi: alacritty.normal().colors(),
+            brights: alacritty.bright().colors(),
+            primary: (fg, bg),
+            cursor: (bg, fg),
+            cursor_border: fg,
+            selection: alacritty.selection(),
+            scrollbar_thumb: hl("PmenuThumb").bg.unwrap(),
+            split: hl("VertSplit").fg.unwrap(),
+            // Composing text is being typed like a pattern of incremental search
+            compose_cursor: hl("IncSearch").bg.unwrap(),
+            // TabLineFill has no background and its foreground fills the tab line
+            tab_bar: hl("TabLineFill").fg.unwrap(),
+            tabs: [
+                ("active_tab", active_tab),
+                ("inactive_tab", inactive_tab),
+                ("inactive_tab_hover", hover_tab),
+                ("new_tab", inactive_tab),
+                ("new_tab_hover", hover_tab),
+            ],
+        }
+    }
+
+    fn color(&self, name: &str) -> &'_ str {
+        self.palette[name].gui.normal()
+    }
+
+    fn write_header(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(
+            w,
+            r#"# WezTerm color scheme for {name} colorscheme
+#
+# Author: rhysd <lin90162@yahoo.co.jp>
+# License: MIT
+#   Copyright (c) 2016 rhysd
+#
+# PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+# Generated by script vim-color-spring-night/gen/{source}"#,
+            name = self.palette.variant().name(),
+            source = file!(),
+        )
+    }
+
+    fn write_option(&self, w: &mut dyn Write, key: &str, name: &str) -> Result<()> {
+        writeln!(w, "{key} = \"{}\"", self.color(name))
+    }
+
+    fn write_array(&self, w: &mut dyn Write, key: &str, names: &[&str]) -> Result<()> {
+        writeln!(w, "{key} = [")?;
+        for name in names {
+            writeln!(w, "    \"{}\",", self.color(name))?;
+        }
+        writeln!(w, "]")
+    }
+
+    fn write_colors_section(&self, w: &mut dyn Write) -> Result<()> {
+        let (fg, bg) = self.primary;
+        let (cursor_fg, cursor_bg) = self.cursor;
+        let (selection_fg, selection_bg) = self.selection;
+        writeln!(w)?;
+        writeln!(w, "[colors]")?;
+        self.write_option(w, "foreground", fg)?;
+        self.write_option(w, "background", bg)?;
+        self.write_option(w, "cursor_fg", cursor_fg)?;
+        self.write_option(w, "cursor_bg", cursor_bg)?;
+        self.write_option(w, "cursor_border", self.cursor_border)?;
+        self.write_option(w, "selection_fg", selection_fg)?;
+        self.write_option(w, "selection_bg", selection_bg)?;
+        self.write_option(w, "scrollbar_thumb", self.scrollbar_thumb)?;
+        self.write_option(w, "split", self.split)?;
+        self.write_option(w, "compose_cursor", self.compose_cursor)?;
+        self.write_array(w, "ansi", &self.ansi)?;
+        self.write_array(w, "brights", &self.brights)
+    }
+
+    fn write_tab_bar_section(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(w)?;
+        writeln!(w, "[colors.tab_bar]")?;
+        self.write_option(w, "background", self.tab_bar)?;
+        for (name, (fg, bg)) in self.tabs {
+            writeln!(w)?;
+            writeln!(w, "[colors.tab_bar.{name}]")?;
+            self.write_option(w, "fg_color", fg)?;
+            self.write_option(w, "bg_color", bg)?;
+        }
+        Ok(())
+    }
+
+    /// Pairs of foreground and background colors rendered by WezTerm
+    pub fn color_pairs(&self) -> Vec<ColorPair<'a>> {
+        let mut pairs = vec![
+            ColorPair::new("primary", self.primary.0, self.primary.1),
+            ColorPair::new("cursor", self.cursor.0, self.cursor.1),
+            ColorPair::new("selection", self.selection.0, self.selection.1),
+        ];
+        for (name, (fg, bg)) in self.tabs {
+            pairs.push(ColorPair::new(format!("tab_bar {name}"), fg, bg));
+        }
+        pairs
+    }
+}
+
+impl ThemeWriter for WezTermTheme<'_> {
+    fn target(&self) -> &'static str {
+        "wezterm"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&["wezterm", &format!("{}.toml", variant.ident())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        self.write_header(w)?;
+        self.write_colors_section(w)?;
+        self.write_tab_bar_section(w)?;
+        writeln!(w)?;
+        writeln!(w, "[metadata]")?;
+        writeln!(w, "name = \"{}\"", self.palette.variant().name())?;
+        writeln!(w, "author = \"rhysd\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::tests::assert_palette_colors;
+    use std::str;
+    use toml_edit::{DocumentMut, Item as TomlItem, Value as TomlValue};
+
+    fn assert_wezterm_theme(p: &Palette) {
+        let w = WezTermTheme::new(p);
+        let mut out = vec![];
+        w.write_to(&mut out).unwrap();
+        let src = str::from_utf8(&out).unwrap();
+        let doc: DocumentMut = src.parse().expect(src);
+        assert_palette_colors(p, src);
+
+        let assert_str = |path: &str, v: &TomlValue| {
+            assert!(v.is_str(), "{path} is not a string: {v:?}");
+        };
+        let get = |path: &str| {
+            let mut item = doc.as_item();
+            for idx in path.split('.') {
+                item = item.get(idx).expect(path);
+            }
+            let TomlItem::Value(v) = item else {
+                panic!("{path} is not a value: {item:?}");
+            };
+            v
+        };
+
+        for key in [
+            "foreground",
+            "background",
+            "cursor_fg",
+            "cursor_bg",
+            "cursor_border",
+            "selection_fg",
+            "selection_bg",
+            "scrollbar_thumb",
+            "split",
+            "compose_cursor",
+            "tab_bar.background",
+        ] {
+            let path = format!("colors.{key}");
+            assert_str(&path, get(&path));
+        }
+
+        for key in ["ansi", "brights"] {
+            let path = format!("colors.{key}");
+            let TomlValue::Array(colors) = get(&path) else {
+                panic!("{path} is not an array");
+            };
+            assert_eq!(colors.len(), 8, "{path}");
+            for color in colors.iter() {
+                assert_str(&path, color);
+            }
+        }
+
+        for tab in [
+            "active_tab",
+            "inactive_tab",
+            "inactive_tab_hover",
+            "new_tab",
+            "new_tab_hover",
+        ] {
+            for key in ["fg_color", "bg_color"] {
+                let path = format!("colors.tab_bar.{tab}.{key}");
+                assert_str(&path, get(&path));
+            }
+        }
+
+        let colorscheme = Colorscheme::new(p);
+        for (tab, group) in [
+            ("active_tab", "TabLineSel"),
+            ("inactive_tab", "TabLine"),
+            ("new_tab", "TabLine"),
+        ] {
+            let hl = colorscheme.resolve(group).unwrap();
+            for (key, name) in [("fg_color", hl.fg), ("bg_color", hl.bg)] {
+                let path = format!("colors.tab_bar.{tab}.{key}");
+                let color = p[name.unwrap()].gui.normal();
+                assert_eq!(get(&path).as_str(), Some(*color), "{path}");
+            }
+        }
+
+        for (key, group) in [
+            ("scrollbar_thumb", "PmenuThumb"),
+            ("compose_cursor", "IncSearch"),
+        ] {
+            let path = format!("colors.{key}");
+            let color = p[colorscheme.resolve(group).unwrap().bg.unwrap()]
+                .gui
+                .normal();
+            assert_eq!(get(&path).as_str(), Some(*color), "{path}");
+        }
+
+        let name = get("metadata.name");
+        assert_eq!(name.as_str(), Some(p.variant().name()));
+    }
+
+    #[test]
+    fn test_default_wezterm_theme() {
+        assert_wezterm_theme(&Palette::default());
+    }
+
+    #[test]
+    fn test_morning_wezterm_theme() {
+        assert_wezterm_theme(&Palette::morning());
+    }
+}
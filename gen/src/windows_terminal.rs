@@ -0,0 +1,120 @@
+use crate::alacritty::AlacrittyTheme;
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+// Keys of ANSI 16 colors in Windows Terminal color scheme
+const ANSI_KEYS: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "purple",
+    "cyan",
+    "white",
+    "brightBlack",
+    "brightRed",
+    "brightGreen",
+    "brightYellow",
+    "brightBlue",
+    "brightPurple",
+    "brightCyan",
+    "brightWhite",
+];
+
+// Color scheme object in "schemes" array of Windows Terminal settings. Colors are shared with
+// `AlacrittyTheme`. JSON does not allow comments so the file has no header.
+#[derive(Debug)]
+pub struct WindowsTerminalTheme<'a> {
+    palette: &'a Palette<'a>,
+    colors: Vec<(&'static str, &'a str)>,
+}
+
+impl<'a> WindowsTerminalTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let alacritty = AlacrittyTheme::new(palette);
+        let (_, selection) = alacritty.selection();
+        let mut colors = vec![
+            ("background", alacritty.background()),
+            ("foreground", alacritty.foreground()),
+            ("cursorColor", alacritty.foreground()),
+            ("selectionBackground", selection),
+        ];
+        colors.extend(ANSI_KEYS.into_iter().zip(alacritty.ansi_colors()));
+        Self { palette, colors }
+    }
+}
+
+impl ThemeWriter for WindowsTerminalTheme<'_> {
+    fn target(&self) -> &'static str {
+        "windows-terminal"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&["windows-terminal", &format!("{}.json", variant.name())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(w, "{{")?;
+        write!(w, "    \"name\": \"{}\"", self.palette.variant().name())?;
+        for (key, name) in &self.colors {
+            write!(
+                w,
+                ",\n    \"{key}\": \"{}\"",
+                self.palette[name].gui.normal()
+            )?;
+        }
+        writeln!(w, "\n}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use serde_json::Value;
+
+    fn assert_scheme(palette: &Palette) {
+        let w = WindowsTerminalTheme::new(palette);
+        let mut out = vec![];
+        w.write_to(&mut out).unwrap();
+        let scheme: Value = serde_json::from_slice(&out).unwrap();
+        let Value::Object(scheme) = scheme else {
+            panic!("scheme is not an object: {scheme:?}");
+        };
+
+        assert_eq!(scheme["name"], palette.variant().name());
+
+        let hex_color = Regex::new(r"^#[[:xdigit:]]{6}$").unwrap();
+        let mut keys = vec![
+            "background",
+            "foreground",
+            "cursorColor",
+            "selectionBackground",
+        ];
+        keys.extend(ANSI_KEYS);
+        for key in &keys {
+            let color = scheme[*key].as_str().expect(key);
+            assert!(hex_color.is_match(color), "{key}: {color:?}");
+        }
+        assert_eq!(scheme.len(), keys.len() + 1, "{scheme:?}");
+
+        let ansi = AlacrittyTheme::new(palette).ansi_colors();
+        for (key, name) in ANSI_KEYS.iter().zip(ansi) {
+            assert_eq!(scheme[*key], *palette[name].gui.normal(), "{key}");
+        }
+    }
+
+    #[test]
+    fn test_default_scheme() {
+        assert_scheme(&Palette::default());
+    }
+
+    #[test]
+    fn test_morning_scheme() {
+        assert_scheme(&Palette::morning());
+    }
+}
@@ -0,0 +1,170 @@
+use crate::color::Rgb;
+use crate::colorscheme::Colorscheme;
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+// X resources for xterm, urxvt, st and so on. Colors are shared with the terminal colors of
+// `Colorscheme` so that terminals look the same as `:terminal` in Vim. Like Neovim's
+// `g:terminal_color_background` and `g:terminal_color_foreground`, color 0 and 7 are used for
+// background and foreground.
+#[derive(Debug)]
+pub struct XresourcesTheme<'a> {
+    palette: &'a Palette<'a>,
+    term_colors: [&'static str; 16],
+}
+
+impl<'a> XresourcesTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let term_colors = *Colorscheme::new(palette).term_colors();
+        Self {
+            palette,
+            term_colors,
+        }
+    }
+
+    fn color(&self, name: &str) -> &'_ str {
+        self.palette[name].gui.normal()
+    }
+}
+
+impl ThemeWriter for XresourcesTheme<'_> {
+    fn target(&self) -> &'static str {
+        "xresources"
+    }
+
+    fn path(&self) -> PathBuf {
+        let variant = self.palette.variant();
+        theme::path(&["xresources", &format!("{}.Xresources", variant.name())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(
+            w,
+            r#"! X resources for {name} colorscheme
+!
+! Author: rhysd <lin90162@yahoo.co.jp>
+! License: MIT
+!   Copyright (c) 2016 rhysd
+!
+! PLEASE DO NOT MODIFY THIS FILE DIRECTLY!
+! Generated by script vim-color-spring-night/gen/{source}
+"#,
+            name = self.palette.variant().name(),
+            source = file!(),
+        )?;
+        let (bg, fg) = (self.term_colors[0], self.term_colors[7]);
+        writeln!(w, "*.foreground: {}", self.color(fg))?;
+        writeln!(w, "*.background: {}", self.color(bg))?;
+        writeln!(w, "*.cursorColor: {}", self.color(fg))?;
+        for (i, name) in self.term_colors.iter().enumerate() {
+            writeln!(w, "*.color{i}: {}", self.color(name))?;
+        }
+        Ok(())
+    }
+}
+
+// Colors of Linux virtual console loaded by `setvtrgb` command. The file consists of 3 lines for
+// red, green and blue components of the 16 colors. The format does not allow comments.
+#[derive(Debug)]
+pub struct VtRgbTheme<'a> {
+    palette: &'a Palette<'a>,
+    term_colors: [&'static str; 16],
+}
+
+impl<'a> VtRgbTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let term_colors = *Colorscheme::new(palette).term_colors();
+        Self {
+            palette,
+            term_colors,
+        }
+    }
+}
+
+impl ThemeWriter for VtRgbTheme<'_> {
+    fn target(&self) -> &'static str {
+        "setvtrgb"
+    }
+
+    fn path(&self) -> PathBuf {
+        theme::path(&["setvtrgb", self.palette.variant().name()])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        let colors: Vec<_> = self
+            .term_colors
+            .iter()
+            .map(|name| Rgb::from_hex(self.palette[name].gui.normal()).unwrap())
+            .collect();
+        for component in [|c: &Rgb| c.r, |c: &Rgb| c.g, |c: &Rgb| c.b] {
+            let line: Vec<_> = colors.iter().map(|c| component(c).to_string()).collect();
+            writeln!(w, "{}", line.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use std::collections::HashMap;
+    use std::str;
+
+    #[test]
+    fn test_write_xresources() {
+        let re_resource = Regex::new(r"^\*\.(\w+): (#[[:xdigit:]]{6})$").unwrap();
+
+        for palette in [Palette::default(), Palette::morning()] {
+            let w = XresourcesTheme::new(&palette);
+            let mut out = vec![];
+            w.write_to(&mut out).unwrap();
+            let rendered = str::from_utf8(&out).unwrap();
+
+            let mut resources = HashMap::new();
+            for line in rendered.lines() {
+                if line.is_empty() || line.starts_with('!') {
+                    continue;
+                }
+                let found = re_resource.captures(line).expect(line);
+                let (key, color) = (found[1].to_string(), found[2].to_string());
+                assert!(resources.insert(key, color).is_none(), "{line}");
+            }
+            assert_eq!(resources.len(), 19, "{resources:?}");
+
+            let term_colors = Colorscheme::new(&palette).term_colors().to_owned();
+            for (i, name) in term_colors.iter().enumerate() {
+                let key = format!("color{i}");
+                assert_eq!(resources[&key], *palette[name].gui.normal(), "{key}");
+            }
+            assert_eq!(resources["background"], resources["color0"]);
+            assert_eq!(resources["foreground"], resources["color7"]);
+            assert_eq!(resources["cursorColor"], resources["color7"]);
+        }
+    }
+
+    #[test]
+    fn test_write_vtrgb() {
+        for palette in [Palette::default(), Palette::morning()] {
+            let w = VtRgbTheme::new(&palette);
+            let mut out = vec![];
+            w.write_to(&mut out).unwrap();
+            let rendered = str::from_utf8(&out).unwrap();
+
+            let components: Vec<Vec<u8>> = rendered
+                .lines()
+                .map(|line| line.split(',').map(|c| c.parse().expect(line)).collect())
+                .collect();
+            assert_eq!(components.len(), 3, "{rendered:?}");
+
+            let term_colors = Colorscheme::new(&palette).term_colors().to_owned();
+            for (i, name) in term_colors.iter().enumerate() {
+                let rgb = Rgb::from_hex(palette[name].gui.normal()).unwrap();
+                let actual = [components[0][i], components[1][i], components[2][i]];
+                assert_eq!(actual, [rgb.r, rgb.g, rgb.b], "color {i}");
+            }
+        }
+    }
+}
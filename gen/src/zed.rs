@@ -0,0 +1,305 @@
+use crate::airline::AirlineTheme;
+use crate::colorscheme::{Colorscheme, HiCommand};
+use crate::palette::Palette;
+use crate::theme::{self, ThemeWriter};
+use std::io::{Result, Write};
+use std::path::PathBuf;
+
+// Keys of ANSI 16 colors of the terminal panel
+const ANSI_KEYS: [&str; 16] = [
+    "terminal.ansi.black",
+    "terminal.ansi.red",
+    "terminal.ansi.green",
+    "terminal.ansi.yellow",
+    "terminal.ansi.blue",
+    "terminal.ansi.magenta",
+    "terminal.ansi.cyan",
+    "terminal.ansi.white",
+    "terminal.ansi.bright_black",
+    "terminal.ansi.bright_red",
+    "terminal.ansi.bright_green",
+    "terminal.ansi.bright_yellow",
+    "terminal.ansi.bright_blue",
+    "terminal.ansi.bright_magenta",
+    "terminal.ansi.bright_cyan",
+    "terminal.ansi.bright_white",
+];
+
+// Zed syntax names and the highlight groups giving their styles
+#[rustfmt::skip]
+const SYNTAX: &[(&str, &str)] = &[
+    ("attribute",             "@attribute"),
+    ("boolean",               "Boolean"),
+    ("comment",               "Comment"),
+    ("comment.doc",           "@comment.documentation"),
+    ("constant",              "Constant"),
+    ("constructor",           "@constructor"),
+    ("emphasis",              "@markup.italic"),
+    ("emphasis.strong",       "@markup.strong"),
+    ("enum",                  "@lsp.type.enum"),
+    ("function",              "Function"),
+    ("keyword",               "Keyword"),
+    ("label",                 "Label"),
+    ("link_text",             "@markup.link"),
+    ("link_uri",              "@markup.link.url"),
+    ("number",                "Number"),
+    ("operator",              "Operator"),
+    ("preproc",               "PreProc"),
+    ("property",              "@property"),
+    ("punctuation",           "@punctuation.delimiter"),
+    ("punctuation.bracket",   "@punctuation.bracket"),
+    ("punctuation.delimiter", "@punctuation.delimiter"),
+    ("punctuation.special",   "@punctuation.special"),
+    ("string",                "String"),
+    ("string.escape",         "@string.escape"),
+    ("string.regex",          "@string.regexp"),
+    ("string.special",        "@string.special"),
+    ("string.special.symbol", "@string.special.symbol"),
+    ("tag",                   "Tag"),
+    ("text.literal",          "@markup.raw"),
+    ("title",                 "Title"),
+    ("type",                  "Type"),
+    ("variable",              "@variable"),
+    ("variable.special",      "@variable.builtin"),
+    ("variant",               "@constructor"),
+];
+
+// Theme family of Zed editor. UI colors follow the highlights of `Colorscheme` and the airline
+// theme, and syntax colors follow the highlights of tree-sitter captures. JSON does not allow
+// comments so the file has no header.
+#[derive(Debug)]
+pub struct ZedTheme<'a> {
+    palette: &'a Palette<'a>,
+    colors: Vec<(&'static str, &'a str)>,
+    player: [(&'static str, &'a str); 3],
+    syntax: Vec<(&'static str, &'a HiCommand)>,
+}
+
+impl<'a> ZedTheme<'a> {
+    pub fn new(palette: &'a Palette) -> Self {
+        let colorscheme = Colorscheme::new(palette);
+        let hl = |name| colorscheme.resolve(name).unwrap();
+        let fg = |name| hl(name).fg.unwrap();
+        let bg = |name| hl(name).bg.unwrap();
+        let [_, _, status_main] = AirlineTheme::new(palette).sections("normal");
+
+        let mut colors = vec![
+            ("background", bg("NormalFloat")),
+            ("border", fg("VertSplit")),
+            ("border.variant", fg("VertSplit")),
+            ("border.focused", fg("FloatBorder")),
+            ("elevated_surface.background", bg("NormalFloat")),
+            ("surface.background", bg("NormalFloat")),
+            ("element.background", bg("Pmenu")),
+            ("element.hover", bg("CursorLine")),
+            ("element.selected", bg("PmenuSel")),
+            ("ghost_element.hover", bg("CursorLine")),
+            ("ghost_element.selected", bg("PmenuSel")),
+            ("text", fg("Normal")),
+            ("text.muted", fg("FloatBorder")),
+            ("text.placeholder", fg("LineNr")),
+            ("text.accent", fg("Title")),
+            ("icon", fg("Normal")),
+            ("icon.muted", fg("FloatBorder")),
+            ("status_bar.background", status_main.1),
+            ("title_bar.background", bg("NormalFloat")),
+            ("toolbar.background", bg("Normal")),
+            ("tab_bar.background", fg("TabLineFill")),
+            ("tab.active_background", bg("TabLineSel")),
+            ("tab.inactive_background", bg("TabLine")),
+            ("panel.background", bg("NormalFloat")),
+            ("pane_group.border", fg("VertSplit")),
+            ("search.match_background", bg("Search")),
+            ("editor.background", bg("Normal")),
+            ("editor.foreground", fg("Normal")),
+            ("editor.gutter.background", bg("SignColumn")),
+            ("editor.active_line.background", bg("CursorLine")),
+            ("editor.line_number", fg("LineNr")),
+            ("editor.active_line_number", fg("CursorLineNr")),
+            ("editor.invisible", fg("SpecialKey")),
+            ("editor.wrap_guide", bg("ColorColumn")),
+            ("editor.active_wrap_guide", bg("ColorColumn")),
+            ("editor.document_highlight.read_background", bg("Search")),
+            ("error", fg("DiagnosticError")),
+            ("warning", fg("DiagnosticWarn")),
+            ("info", fg("DiagnosticInfo")),
+            ("hint", fg("DiagnosticHint")),
+            ("created", fg("SignifySignAdd")),
+            ("modified", fg("SignifySignChange")),
+            ("deleted", fg("SignifySignDelete")),
+        ];
+
+        // Terminal is the same as `:terminal` in Vim
+        let term_colors = colorscheme.term_colors();
+        colors.push(("terminal.background", term_colors[0]));
+        colors.push(("terminal.foreground", term_colors[7]));
+        colors.extend(ANSI_KEYS.into_iter().zip(*term_colors));
+
+        let player = [
+            ("cursor", bg("Cursor")),
+            ("background", bg("Cursor")),
+            ("selection", bg("Visual")),
+        ];
+
+        let syntax = SYNTAX
+            .iter()
+            .map(|&(name, group)| (name, hl(group)))
+            .collect();
+
+        Self {
+            palette,
+            colors,
+            player,
+            syntax,
+        }
+    }
+
+    fn color(&self, name: &str) -> &'_ str {
+        self.palette[name].gui.normal()
+    }
+
+    fn write_syntax(&self, w: &mut dyn Write) -> Result<()> {
+        writeln!(w, "        \"syntax\": {{")?;
+        for (i, (name, cmd)) in self.syntax.iter().enumerate() {
+            let mut style = vec![];
+            if let Some(fg) = cmd.fg.filter(|&c| c != "NONE") {
+                style.push(format!("\"color\": \"{}\"", self.color(fg)));
+            }
+            let font_styles = cmd.attr.font_styles();
+            if font_styles.contains(&"italic") {
+                style.push("\"font_style\": \"italic\"".to_string());
+            }
+            if font_styles.contains(&"bold") {
+                style.push("\"font_weight\": 700".to_string());
+            }
+            let comma = if i + 1 < self.syntax.len() { "," } else { "" };
+            writeln!(w, "          \"{name}\": {{ {} }}{comma}", style.join(", "))?;
+        }
+        writeln!(w, "        }}")
+    }
+}
+
+impl ThemeWriter for ZedTheme<'_> {
+    fn target(&self) -> &'static str {
+        "zed"
+    }
+
+    fn path(&self) -> PathBuf {
+        theme::path(&["zed", &format!("{}.json", self.palette.variant().name())])
+    }
+
+    fn write_to(&self, w: &mut dyn Write) -> Result<()> {
+        let variant = self.palette.variant();
+        writeln!(w, "{{")?;
+        writeln!(
+            w,
+            "  \"$schema\": \"https://zed.dev/schema/themes/v0.2.0.json\","
+        )?;
+        writeln!(w, "  \"name\": \"{}\",", variant.name())?;
+        writeln!(w, "  \"author\": \"rhysd\",")?;
+        writeln!(w, "  \"themes\": [")?;
+        writeln!(w, "    {{")?;
+        writeln!(w, "      \"name\": \"{}\",", variant.name())?;
+        writeln!(w, "      \"appearance\": \"{}\",", variant.background())?;
+        writeln!(w, "      \"style\": {{")?;
+        for (key, name) in &self.colors {
+            writeln!(w, "        \"{key}\": \"{}\",", self.color(name))?;
+        }
+        let player: Vec<_> = self
+            .player
+            .iter()
+            .map(|(key, name)| format!("\"{key}\": \"{}\"", self.color(name)))
+            .collect();
+        writeln!(w, "        \"players\": [{{ {} }}],", player.join(", "))?;
+        self.write_syntax(w)?;
+        writeln!(w, "      }}")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "  ]")?;
+        writeln!(w, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use serde_json::Value;
+
+    // Check all colors in the value recursively and return the number of them
+    fn assert_colors(re: &Regex, path: &str, value: &Value) -> usize {
+        match value {
+            Value::String(s) => {
+                assert!(re.is_match(s), "{path}: {s:?}");
+                1
+            }
+            Value::Array(a) => a
+                .iter()
+                .enumerate()
+                .map(|(i, v)| assert_colors(re, &format!("{path}[{i}]"), v))
+                .sum(),
+            Value::Object(o) => o
+                .iter()
+                .filter(|(k, _)| *k != "font_style")
+                .map(|(k, v)| assert_colors(re, &format!("{path}.{k}"), v))
+                .sum(),
+            _ => 0,
+        }
+    }
+
+    fn assert_theme(palette: &Palette) {
+        let w = ZedTheme::new(palette);
+        let mut out = vec![];
+        w.write_to(&mut out).unwrap();
+        let family: Value = serde_json::from_slice(&out).unwrap();
+
+        let variant = palette.variant();
+        assert_eq!(family["name"], variant.name());
+        let themes = family["themes"].as_array().unwrap();
+        assert_eq!(themes.len(), 1);
+        let theme = &themes[0];
+        assert_eq!(theme["name"], variant.name());
+        assert_eq!(theme["appearance"], variant.background());
+
+        let style = &theme["style"];
+        let hex_color = Regex::new(r"^#[[:xdigit:]]{6}([[:xdigit:]]{2})?$").unwrap();
+        let num_colors = assert_colors(&hex_color, "style", style);
+        assert!(num_colors > w.colors.len(), "{num_colors}");
+
+        for key in [
+            "background",
+            "border",
+            "editor.background",
+            "editor.foreground",
+            "editor.gutter.background",
+            "status_bar.background",
+            "terminal.background",
+            "terminal.ansi.black",
+            "terminal.ansi.bright_white",
+        ] {
+            assert!(style[key].is_string(), "{key} is missing");
+        }
+        assert_eq!(style["editor.background"], *palette["bg"].gui.normal());
+
+        let syntax = style["syntax"].as_object().unwrap();
+        assert_eq!(syntax.len(), SYNTAX.len());
+        for (name, s) in syntax {
+            let s = s.as_object().unwrap();
+            assert!(!s.is_empty(), "{name}");
+            if let Some(font_style) = s.get("font_style") {
+                assert_eq!(font_style, "italic", "{name}");
+            }
+        }
+        assert_eq!(syntax["string"]["color"], *palette["green"].gui.normal());
+        assert_eq!(syntax["keyword"]["font_weight"], 700);
+    }
+
+    #[test]
+    fn test_default_theme() {
+        assert_theme(&Palette::default());
+    }
+
+    #[test]
+    fn test_morning_theme() {
+        assert_theme(&Palette::morning());
+    }
+}